@@ -1,3 +1,11 @@
 mod file_format;
+mod genetic_code;
+mod letter_case;
+mod line_ending;
+mod nucleotide;
 
 pub use file_format::FileFormat;
+pub use genetic_code::GeneticCode;
+pub use letter_case::LetterCase;
+pub use line_ending::LineEnding;
+pub use nucleotide::{complement, reverse_complement};
@@ -0,0 +1,225 @@
+/// Codon table as published by the NCBI, see
+/// <https://www.ncbi.nlm.nih.gov/Taxonomy/Utils/wprintgc.cgi>.
+///
+/// Amino acids and start codons are listed in the NCBI order of codons, with the bases of each
+/// position iterating `T`, `C`, `A`, `G`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneticCode {
+    id: u8,
+    name: &'static str,
+    amino_acids: &'static str,
+    starts: &'static str,
+}
+
+const fn code(
+    id: u8,
+    name: &'static str,
+    amino_acids: &'static str,
+    starts: &'static str,
+) -> GeneticCode {
+    GeneticCode {
+        id,
+        name,
+        amino_acids,
+        starts,
+    }
+}
+
+#[rustfmt::skip]
+const GENETIC_CODES: &[GeneticCode] = &[
+    code(1, "Standard",
+        "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**--*----M---------------M----------------------------"),
+    code(2, "Vertebrate Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+        "----------**--------------------MMMM----------**---M------------"),
+    code(3, "Yeast Mitochondrial",
+        "FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**----------------------MM---------------M------------"),
+    code(4, "Mold, Protozoan, and Coelenterate Mitochondrial and Mycoplasma/Spiroplasma",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--MM------**-------M------------MMMM---------------M------------"),
+    code(5, "Invertebrate Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+        "---M------**--------------------MMMM---------------M------------"),
+    code(6, "Ciliate, Dasycladacean and Hexamita Nuclear",
+        "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------"),
+    code(9, "Echinoderm and Flatworm Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "----------**-----------------------M---------------M------------"),
+    code(10, "Euplotid Nuclear",
+        "FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**-----------------------M----------------------------"),
+    code(11, "Bacterial, Archaeal and Plant Plastid",
+        "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**--*----M------------MMMM---------------M------------"),
+    code(12, "Alternative Yeast Nuclear",
+        "FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*----M---------------M----------------------------"),
+    code(13, "Ascidian Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+        "---M------**----------------------MM---------------M------------"),
+    code(14, "Alternative Flatworm Mitochondrial",
+        "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "-----------*-----------------------M----------------------------"),
+    code(16, "Chlorophycean Mitochondrial",
+        "FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------*---*--------------------M----------------------------"),
+    code(21, "Trematode Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "----------**-----------------------M---------------M------------"),
+    code(22, "Scenedesmus obliquus Mitochondrial",
+        "FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "------*---*---*--------------------M----------------------------"),
+    code(23, "Thraustochytrium Mitochondrial",
+        "FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--*-------**--*-----------------M--M---------------M------------"),
+    code(24, "Rhabdopleuridae Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        "---M------**-------M---------------M---------------M------------"),
+    code(25, "Candidate Division SR1 and Gracilibacteria",
+        "FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**-----------------------M---------------M------------"),
+    code(26, "Pachysolen tannophilus Nuclear",
+        "FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*----M---------------M----------------------------"),
+    code(27, "Karyorelict Nuclear",
+        "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------"),
+    code(28, "Condylostoma Nuclear",
+        "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*--------------------M----------------------------"),
+    code(29, "Mesodinium Nuclear",
+        "FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------"),
+    code(30, "Peritrich Nuclear",
+        "FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------"),
+    code(31, "Blastocrithidia Nuclear",
+        "FFLLSSSSYYEECCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**-----------------------M----------------------------"),
+    code(32, "Balanophoraceae Plastid",
+        "FFLLSSSSYY*WCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------*---*----M------------MMMM---------------M------------"),
+    code(33, "Cephalodiscidae Mitochondrial",
+        "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        "---M-------*-------M---------------M---------------M------------"),
+];
+
+impl GeneticCode {
+    /// Table used when a record does not specify one.
+    pub fn standard() -> &'static Self {
+        &GENETIC_CODES[0]
+    }
+
+    /// Looks up a table by its NCBI identifier, as used by the `/transl_table` qualifier.
+    pub fn from_id(id: u8) -> Option<&'static Self> {
+        GENETIC_CODES.iter().find(|code| code.id == id)
+    }
+
+    pub fn all() -> impl Iterator<Item = &'static Self> {
+        GENETIC_CODES.iter()
+    }
+
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Amino acid encoded by the codon, `*` for stop codons. Ambiguous bases are resolved if
+    /// all possible codons encode the same amino acid, otherwise `X` is returned.
+    pub fn translate_codon(&self, codon: &str) -> char {
+        self.lookup(codon, self.amino_acids).unwrap_or('X')
+    }
+
+    /// Whether the codon can initiate translation.
+    pub fn is_start_codon(&self, codon: &str) -> bool {
+        self.lookup(codon, self.starts) == Some('M')
+    }
+
+    pub fn is_stop_codon(&self, codon: &str) -> bool {
+        self.translate_codon(codon) == '*'
+    }
+
+    /// Translates the sequence codon by codon; trailing bases that do not form a complete
+    /// codon are ignored.
+    pub fn translate(&self, sequence: &str) -> String {
+        sequence
+            .as_bytes()
+            .chunks_exact(3)
+            .map(|codon| {
+                std::str::from_utf8(codon).map_or('X', |codon| self.translate_codon(codon))
+            })
+            .collect()
+    }
+
+    fn lookup(&self, codon: &str, table: &str) -> Option<char> {
+        let [first, second, third] = codon.as_bytes() else {
+            return None;
+        };
+
+        let mut result = None;
+        for first in base_indices(*first) {
+            for second in base_indices(*second) {
+                for third in base_indices(*third) {
+                    let value = table.as_bytes()[first * 16 + second * 4 + third] as char;
+                    if result.is_some_and(|result| result != value) {
+                        return None;
+                    }
+                    result = Some(value);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Indices of the bases an IUPAC nucleotide code stands for, in the `T`, `C`, `A`, `G` order.
+fn base_indices(base: u8) -> &'static [usize] {
+    match base.to_ascii_uppercase() {
+        b'T' | b'U' => &[0],
+        b'C' => &[1],
+        b'A' => &[2],
+        b'G' => &[3],
+        b'Y' => &[0, 1],
+        b'W' => &[0, 2],
+        b'K' => &[0, 3],
+        b'M' => &[1, 2],
+        b'S' => &[1, 3],
+        b'R' => &[2, 3],
+        b'H' => &[0, 1, 2],
+        b'B' => &[0, 1, 3],
+        b'D' => &[0, 2, 3],
+        b'V' => &[1, 2, 3],
+        b'N' => &[0, 1, 2, 3],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genetic_code() {
+        assert!(
+            GeneticCode::all().all(|code| code.amino_acids.len() == 64 && code.starts.len() == 64)
+        );
+
+        let standard = GeneticCode::standard();
+        assert_eq!(standard.translate("ATGGCCTTTtaaG"), "MAF*");
+        assert_eq!(standard.translate_codon("GCN"), 'A');
+        assert_eq!(standard.translate_codon("TAR"), '*');
+        assert_eq!(standard.translate_codon("NNN"), 'X');
+        assert!(standard.is_start_codon("TTG") && !standard.is_start_codon("GTG"));
+
+        let mitochondrial = GeneticCode::from_id(2).unwrap();
+        assert_eq!(mitochondrial.translate("TGAAGAATA"), "W*M");
+        assert!(GeneticCode::from_id(11).unwrap().is_start_codon("GTG"));
+        assert!(GeneticCode::from_id(7).is_none());
+    }
+}
@@ -0,0 +1,19 @@
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LetterCase {
+    #[default]
+    Preserve,
+    Upper,
+    Lower,
+}
+
+impl LetterCase {
+    pub fn apply<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        match self {
+            LetterCase::Preserve => Cow::Borrowed(value),
+            LetterCase::Upper => Cow::Owned(value.to_ascii_uppercase()),
+            LetterCase::Lower => Cow::Owned(value.to_ascii_lowercase()),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/// Complementary base of a nucleotide, including the IUPAC ambiguity codes. The letter case is
+/// preserved and unknown characters are returned unchanged.
+pub fn complement(base: char) -> char {
+    let complement = match base.to_ascii_uppercase() {
+        'A' => 'T',
+        'T' | 'U' => 'A',
+        'G' => 'C',
+        'C' => 'G',
+        'R' => 'Y',
+        'Y' => 'R',
+        'K' => 'M',
+        'M' => 'K',
+        'B' => 'V',
+        'V' => 'B',
+        'D' => 'H',
+        'H' => 'D',
+        _ => return base,
+    };
+
+    if base.is_ascii_lowercase() {
+        complement.to_ascii_lowercase()
+    } else {
+        complement
+    }
+}
+
+/// Reverse complement of a nucleotide sequence, see [`complement`].
+pub fn reverse_complement(sequence: &str) -> String {
+    sequence.chars().rev().map(complement).collect()
+}
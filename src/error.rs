@@ -1,5 +1,7 @@
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
     #[cfg(feature = "fasta")]
     #[error("FASTA error: {0}")]
     FastaError(#[from] Box<crate::fasta::Error>),
@@ -9,6 +11,9 @@ pub enum Error {
     #[cfg(feature = "genbank")]
     #[error("Genbank error: {0}")]
     GenbankError(#[from] Box<crate::genbank::Error>),
+    #[cfg(feature = "sam")]
+    #[error("SAM error: {0}")]
+    SamError(#[from] Box<crate::sam::Error>),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -3,7 +3,9 @@
 mod error;
 mod fasta;
 mod fasta_metadata;
+mod fasta_reader;
 mod fasta_sequence;
+mod fasta_writer;
 mod parser;
 
 pub(crate) use self::error::{Error, Result};
@@ -11,4 +13,6 @@ pub(crate) use self::parser::Rule;
 
 pub use self::fasta::Fasta;
 pub use self::fasta_metadata::FastaMetadata;
+pub use self::fasta_reader::FastaReader;
 pub use self::fasta_sequence::FastaSequence;
+pub use self::fasta_writer::FastaWriter;
@@ -4,7 +4,7 @@ use crate::{helper::*, FileFormat};
 
 use super::{
     parser::{FastaParser, Rule},
-    FastaMetadata, FastaSequence,
+    FastaMetadata, FastaSequence, FastaWriter,
 };
 
 #[derive(Debug)]
@@ -25,6 +25,11 @@ impl Fasta {
     pub fn sequences_into_iter(self) -> impl Iterator<Item = FastaSequence> {
         self.sequences.into_iter()
     }
+
+    /// Writes all sequences using the default [`FastaWriter`] settings.
+    pub fn write<W: std::io::Write>(&self, writer: &mut W) -> crate::Result<()> {
+        FastaWriter::default().write(writer, self)
+    }
 }
 
 impl Fasta {
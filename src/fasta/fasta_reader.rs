@@ -0,0 +1,102 @@
+use std::io::{self, BufRead};
+
+use crate::helper::LineReader;
+
+use super::{Fasta, FastaSequence, Rule};
+
+/// Reads FASTA sequences one at a time from a [`BufRead`] source.
+///
+/// Only a single record is held in memory at a time. Each record is parsed with
+/// [`Fasta::parse`], so the results match parsing the whole input at once.
+#[derive(Debug)]
+pub struct FastaReader<R> {
+    lines: LineReader<R>,
+    failed: bool,
+}
+
+impl<R: BufRead> FastaReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: LineReader::new(reader),
+            failed: false,
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<String>> {
+        let mut record = String::new();
+        if !self.lines.append_line(&mut record)? {
+            return Ok(None);
+        }
+        self.lines
+            .append_lines_while(&mut record, |line| !line.starts_with('>'))?;
+
+        Ok(Some(record))
+    }
+
+    fn parse_record(record: &str) -> crate::Result<FastaSequence> {
+        Fasta::parse(record)?
+            .sequences_into_iter()
+            .next()
+            .ok_or_else(|| {
+                Box::new(super::Error::FastaCompileError {
+                    expected: Some(Rule::singleseq_def),
+                    actual: None,
+                })
+                .into()
+            })
+    }
+}
+
+impl<R: BufRead> Iterator for FastaReader<R> {
+    type Item = crate::Result<FastaSequence>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.read_record() {
+            Ok(record) => record.map(|record| Self::parse_record(&record)),
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_fasta_reader() {
+        let source = indoc! {"
+            >Rosalind_6404
+            CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCT
+            CTGAGGCTTCCGGCCTTCCCTCCCACTAATAATTCTGAGG
+
+            >Rosalind_5959
+            CCATCGGTAGCGCATCCTTAGTCCAATTAAGTCCCTATCC
+            >Rosalind_0808
+            CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAA
+
+        "};
+        let expected = Fasta::parse(source).unwrap();
+        let streamed = FastaReader::new(source.as_bytes())
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(streamed.len(), 3);
+        for (expected, streamed) in expected.sequences_iter().zip(&streamed) {
+            assert_eq!(expected.sequence_name(), streamed.sequence_name());
+            assert_eq!(expected.sequence_str(), streamed.sequence_str());
+        }
+
+        let mut reader = FastaReader::new(">broken\n>Rosalind_5959\nACGT\n".as_bytes());
+        assert!(reader.next().unwrap().is_err());
+        assert_eq!(reader.next().unwrap().unwrap().sequence_str(), "ACGT");
+    }
+}
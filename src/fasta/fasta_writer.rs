@@ -0,0 +1,140 @@
+use std::io::Write;
+
+use crate::{helper::wrap_lines, LetterCase, LineEnding};
+
+use super::{Fasta, FastaSequence};
+
+#[derive(Debug, Clone)]
+pub struct FastaWriter {
+    line_width: Option<usize>,
+    line_ending: LineEnding,
+    letter_case: LetterCase,
+}
+
+impl Default for FastaWriter {
+    fn default() -> Self {
+        Self {
+            line_width: Some(60),
+            line_ending: LineEnding::default(),
+            letter_case: LetterCase::default(),
+        }
+    }
+}
+
+impl FastaWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of sequence characters per line.
+    /// `None` (or `Some(0)`) writes each sequence on a single line.
+    pub fn line_width(mut self, line_width: Option<usize>) -> Self {
+        self.line_width = line_width.filter(|width| *width > 0);
+        self
+    }
+
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Sets the letter case of the written sequences. Headers are never modified.
+    pub fn letter_case(mut self, letter_case: LetterCase) -> Self {
+        self.letter_case = letter_case;
+        self
+    }
+}
+
+impl FastaWriter {
+    pub fn write<W: Write>(&self, writer: &mut W, fasta: &Fasta) -> crate::Result<()> {
+        for sequence in fasta.sequences_iter() {
+            self.write_sequence(writer, sequence)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_sequence<W: Write>(
+        &self,
+        writer: &mut W,
+        sequence: &FastaSequence,
+    ) -> crate::Result<()> {
+        let mut buffer = String::new();
+        self.format_sequence(&mut buffer, sequence);
+        writer.write_all(buffer.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn write_to_string(&self, fasta: &Fasta) -> String {
+        let mut buffer = String::new();
+        for sequence in fasta.sequences_iter() {
+            self.format_sequence(&mut buffer, sequence);
+        }
+        buffer
+    }
+
+    fn format_sequence(&self, buffer: &mut String, sequence: &FastaSequence) {
+        let line_ending = self.line_ending.as_str();
+
+        buffer.push('>');
+        buffer.push_str(sequence.sequence_name());
+        buffer.push_str(line_ending);
+
+        let residues = self.letter_case.apply(sequence.sequence_str());
+        for line in wrap_lines(&residues, self.line_width) {
+            buffer.push_str(line);
+            buffer.push_str(line_ending);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_fasta_write() {
+        let source = indoc! {"
+            >Rosalind_6404
+            CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCT
+            CTGAGGCTTCCGGCCTTCCCTCCCACTAATAATTCTGAGG
+            >Rosalind_5959
+            CCATCGGTAGCGCATCCTTAGTCCAATTAAGTCCCTATCC
+            AGGCGCTCCGCCGAAGGTCTATATCCATTTGTCAGCAGAC
+            ACGC
+        "};
+        let fasta = Fasta::parse(source).unwrap();
+
+        let written = FastaWriter::new()
+            .line_width(Some(40))
+            .write_to_string(&fasta);
+        assert_eq!(written, source);
+
+        let written = FastaWriter::new()
+            .line_width(None)
+            .line_ending(LineEnding::CrLf)
+            .letter_case(LetterCase::Lower)
+            .write_to_string(&fasta);
+        assert_eq!(
+            written,
+            ">Rosalind_6404\r\ncctgcggaagatcggcactagaatagccagaaccgtttctctgaggcttccggccttccctcccactaataattctgagg\r\n\
+             >Rosalind_5959\r\nccatcggtagcgcatccttagtccaattaagtccctatccaggcgctccgccgaaggtctatatccatttgtcagcagacacgc\r\n"
+        );
+
+        let reparsed = Fasta::parse(&written).unwrap();
+        for (original, reparsed) in fasta.sequences_iter().zip(reparsed.sequences_iter()) {
+            assert_eq!(original.sequence_name(), reparsed.sequence_name());
+            assert!(original
+                .sequence_str()
+                .eq_ignore_ascii_case(reparsed.sequence_str()));
+        }
+
+        let mut buffer = Vec::new();
+        fasta.write(&mut buffer).unwrap();
+        let reparsed = Fasta::parse(std::str::from_utf8(&buffer).unwrap()).unwrap();
+        assert_eq!(reparsed.sequences_iter().count(), 2);
+    }
+}
@@ -2,8 +2,14 @@
 
 mod error;
 mod fastq;
+mod fastq_header;
 mod fastq_metadata;
+mod fastq_quality;
+mod fastq_reader;
 mod fastq_sequence;
+mod fastq_statistics;
+mod fastq_trimmer;
+mod fastq_writer;
 mod parser;
 
 pub(crate) use self::error::{Error, Result};
@@ -11,5 +17,15 @@ pub(crate) use self::parser::Rule;
 
 pub use self::fastq::Fastq;
 
+pub use self::fastq_header::{
+    FastqHeader, FastqIlluminaHeader, FastqNanoporeHeader, FastqPairedHeader,
+};
 pub use self::fastq_metadata::FastqMetadata;
+pub use self::fastq_quality::FastqQualityEncoding;
+pub use self::fastq_reader::FastqReader;
 pub use self::fastq_sequence::FastqSequence;
+pub use self::fastq_statistics::{
+    FastqOverrepresentedSequence, FastqQualityQuantiles, FastqStatistics, FastqSummary,
+};
+pub use self::fastq_trimmer::{FastqTrimmed, FastqTrimmer};
+pub use self::fastq_writer::FastqWriter;
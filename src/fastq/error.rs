@@ -1,6 +1,7 @@
 type FastqParseError = pest::error::Error<super::Rule>;
 
 #[derive(Debug, thiserror::Error)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     #[error("FASTQ parsing error: {0}")]
     FastaParseError(#[from] FastqParseError),
@@ -9,6 +10,17 @@ pub enum Error {
         expected: Option<super::Rule>,
         actual: Option<super::Rule>,
     },
+    #[error("FASTQ length mismatch in record {sequence_name:?}: sequence has {sequence_length} characters, quality has {quality_length}")]
+    FastqLengthMismatch {
+        sequence_name: String,
+        sequence_length: usize,
+        quality_length: usize,
+    },
+    #[error("FASTQ header mismatch: quality header {quality_header:?} differs from sequence header {sequence_header:?}")]
+    FastqHeaderMismatch {
+        sequence_header: String,
+        quality_header: String,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, Box<Error>>;
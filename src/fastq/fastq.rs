@@ -4,7 +4,7 @@ use crate::{helper::*, FileFormat};
 
 use super::{
     parser::{FastqParser, Rule},
-    FastqMetadata, FastqSequence,
+    FastqMetadata, FastqReader, FastqSequence, FastqWriter,
 };
 
 #[derive(Debug)]
@@ -22,66 +22,95 @@ impl Fastq {
         self.sequences.iter()
     }
 
+    pub fn sequences_iter_mut(&mut self) -> impl Iterator<Item = &mut FastqSequence> {
+        self.sequences.iter_mut()
+    }
+
     pub fn sequences_into_iter(self) -> impl Iterator<Item = FastqSequence> {
         self.sequences.into_iter()
     }
+
+    /// Writes all sequences using the default [`FastqWriter`] settings.
+    pub fn write<W: std::io::Write>(&self, writer: &mut W) -> crate::Result<()> {
+        FastqWriter::default().write(writer, self)
+    }
 }
 
 impl Fastq {
+    /// Parses all records of the source. Like [`FastqReader`], records are delimited by reading
+    /// quality lines until they cover the sequence length.
     pub fn parse(source: &str) -> crate::Result<Self> {
-        let root = FastqParser::parse(Rule::root, source)
+        let sequences = FastqReader::new(source.as_bytes()).collect::<crate::Result<_>>()?;
+        Ok(Self { sequences })
+    }
+
+    /// Parses the lines of a single record.
+    pub(crate) fn parse_record(record: &str) -> crate::Result<FastqSequence> {
+        let root = FastqParser::parse(Rule::record, record)
             .map_err(|err| Box::new(super::Error::FastaParseError(err)))?
             .next()
             .ok_or_else(|| {
                 Box::new(super::Error::FastqCompileError {
-                    expected: Some(Rule::root),
+                    expected: Some(Rule::record),
                     actual: None,
                 })
             })?;
 
-        Ok(Self {
-            sequences: Self::parse_root(root)?,
-        })
+        Ok(Self::parse_root(root)?)
     }
 
-    fn parse_root(root: Pair<Rule>) -> super::Result<Vec<FastqSequence>> {
-        let mut sequences = Vec::new();
-
-        for pair in root.into_inner() {
-            match pair.as_rule() {
-                Rule::multiseq_def => {
-                    for pair in pair.into_inner() {
-                        sequences.push(Self::parse_definition(pair)?)
-                    }
-                }
-                Rule::EOI => break,
-                _ => unreachable!(),
-            }
-        }
+    fn parse_root(root: Pair<Rule>) -> super::Result<FastqSequence> {
+        let mut pairs = root.into_inner();
+        let sequence = Self::parse_definition(pairs.next().expect_some(Rule::singleseq_def)?)?;
+        pairs.next().expect_some(Rule::EOI)?;
 
-        Ok(sequences)
+        Ok(sequence)
     }
 
     fn parse_definition(singleseq_def: Pair<Rule>) -> super::Result<FastqSequence> {
         let pair = singleseq_def.expect(Rule::singleseq_def)?;
         let mut pairs = pair.into_inner();
 
-        let metadata = Self::parse_definition_metadata(pairs.next())?;
+        let sequence_header = pairs.next();
         let sequence = Self::parse_definition_sequence(pairs.next())?;
-        let _quality_header = pairs.next().expect_some(Rule::quality_header)?;
+        let quality_header = pairs.next();
+        let metadata = Self::parse_definition_metadata(sequence_header, quality_header)?;
         let quality = Self::parse_definition_quality(pairs.next())?;
         pairs.next().expect_none()?;
 
+        if sequence.len() != quality.len() {
+            return Err(Box::new(super::Error::FastqLengthMismatch {
+                sequence_name: metadata.sequence_name().to_string(),
+                sequence_length: sequence.len(),
+                quality_length: quality.len(),
+            }));
+        }
+
         Ok(FastqSequence::new(metadata, sequence, quality))
     }
 
     fn parse_definition_metadata(
         sequence_header: Option<Pair<Rule>>,
+        quality_header: Option<Pair<Rule>>,
     ) -> super::Result<FastqMetadata> {
         let pair = sequence_header.expect_some(Rule::sequence_header)?;
         let sequence_header = pair.as_str().trim_start_matches('@').to_string();
 
-        Ok(FastqMetadata::new(sequence_header))
+        let pair = quality_header.expect_some(Rule::quality_header)?;
+        let quality_header = pair.as_str().trim_start_matches('+');
+        let quality_header = (!quality_header.is_empty()).then(|| quality_header.to_string());
+
+        // The `+` line either repeats the `@` line or is left empty
+        if let Some(quality_header) = quality_header.as_ref() {
+            if *quality_header != sequence_header {
+                return Err(Box::new(super::Error::FastqHeaderMismatch {
+                    sequence_header,
+                    quality_header: quality_header.clone(),
+                }));
+            }
+        }
+
+        Ok(FastqMetadata::new(sequence_header, quality_header))
     }
 
     fn parse_definition_sequence(sequence_multiline: Option<Pair<Rule>>) -> super::Result<String> {
@@ -115,6 +144,10 @@ mod tests {
         let fastq = Fastq::parse(source).unwrap();
         assert_eq!(fastq.sequences.len(), 1);
         assert_eq!(fastq.sequences[0].sequence_name(), "Rosalind_6404");
+        assert_eq!(
+            fastq.sequences[0].metadata().quality_header(),
+            Some("Rosalind_6404")
+        );
         assert_eq!(
             fastq.sequences[0].sequence_str(),
             "CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCTCTGAGGCTTCCGGCCTTCCCTCCCACTAATAATTCTGAGG"
@@ -169,4 +202,48 @@ mod tests {
             "+IIIIIIIIIIIII****((((((***+))%%%++)(%%%%).1***-+*''))**55CCF>>>>>>CCCCCCC65IIII"
         );
     }
+
+    #[test]
+    fn test_fastq_parse_by_length() {
+        let source = indoc! {"
+            @read_1
+            ACGTACGTAC
+            GTACGT
+            +
+            IIIIIIIIII
+            @IIII
+            +
+            @read_2
+            ACGT
+            +read_2
+            +III
+        "};
+        let fastq = Fastq::parse(source).unwrap();
+        assert_eq!(fastq.sequences.len(), 2);
+        assert_eq!(fastq.sequences[0].sequence_str(), "ACGTACGTACGTACGT");
+        assert_eq!(fastq.sequences[0].quality_str(), "IIIIIIIIII@IIII+");
+        assert_eq!(fastq.sequences[1].sequence_name(), "read_2");
+        assert_eq!(fastq.sequences[1].quality_str(), "+III");
+
+        let source = indoc! {"
+            @read_1
+            ACGTACGT
+            +
+            IIIIIII
+            @read_2
+            ACGT
+            +
+            IIII
+        "};
+        let error = Fastq::parse(source).unwrap_err();
+        assert!(error.to_string().contains("\"read_1\""));
+
+        let source = indoc! {"
+            @read_1
+            ACGT
+            +read_2
+            IIII
+        "};
+        assert!(Fastq::parse(source).is_err());
+    }
 }
@@ -0,0 +1,385 @@
+use super::FastqMetadata;
+
+/// Fields of a sequence header following a known naming convention, see
+/// [`FastqMetadata::parsed_header`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FastqHeader {
+    /// Illumina CASAVA 1.8+, e.g. `EAS139:136:FC706VJ:2:2104:15343:197393 1:Y:18:ATCACG`.
+    Illumina(FastqIlluminaHeader),
+    /// Read name with a `/1` or `/2` mate suffix, as written by Illumina before CASAVA 1.8,
+    /// e.g. `HWUSI-EAS100R:6:73:941:1973#0/1`.
+    Paired(FastqPairedHeader),
+    /// Oxford Nanopore read ID followed by `key=value` pairs, e.g.
+    /// `0ab5f1b4-d2e0-4b0c-9a48-e2f7a0b0e0c1 runid=a8f2 read=17 ch=489 barcode=barcode01`.
+    Nanopore(FastqNanoporeHeader),
+    /// Any other header, with its first word as the read name.
+    Other(String),
+}
+
+impl FastqHeader {
+    /// Name shared by the mates of a read pair, without the read number.
+    pub fn read_name(&self) -> &str {
+        match self {
+            FastqHeader::Illumina(header) => &header.read_name,
+            FastqHeader::Paired(header) => &header.read_name,
+            FastqHeader::Nanopore(header) => &header.read_id,
+            FastqHeader::Other(read_name) => read_name,
+        }
+    }
+
+    /// Mate of a read pair, usually 1 or 2.
+    pub fn read_number(&self) -> Option<u8> {
+        match self {
+            FastqHeader::Illumina(header) => header.read_number,
+            FastqHeader::Paired(header) => Some(header.read_number),
+            FastqHeader::Nanopore(_) | FastqHeader::Other(_) => None,
+        }
+    }
+
+    /// Sample index or barcode used for demultiplexing, `None` for reads that were not
+    /// multiplexed.
+    pub fn barcode(&self) -> Option<&str> {
+        match self {
+            FastqHeader::Illumina(header) => header.index(),
+            FastqHeader::Paired(header) => header.index().filter(|index| *index != "0"),
+            FastqHeader::Nanopore(header) => header.barcode(),
+            FastqHeader::Other(_) => None,
+        }
+    }
+}
+
+impl From<&str> for FastqHeader {
+    fn from(header: &str) -> Self {
+        let header = header.trim();
+        let (name, comment) = header
+            .split_once(char::is_whitespace)
+            .map_or((header, ""), |(name, comment)| (name, comment.trim_start()));
+
+        if let Some(header) = FastqIlluminaHeader::parse(name, comment) {
+            return FastqHeader::Illumina(header);
+        }
+        if let Some(header) = FastqNanoporeHeader::parse(name, comment) {
+            return FastqHeader::Nanopore(header);
+        }
+        if let Some(header) = FastqPairedHeader::parse(name) {
+            return FastqHeader::Paired(header);
+        }
+        FastqHeader::Other(name.to_string())
+    }
+}
+
+/// Header in the CASAVA 1.8+ format
+/// `instrument:run:flowcell:lane:tile:x:y[:umi] read:filtered:control:index`.
+///
+/// The part after the space is missing from some archives, in which case its fields are
+/// `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastqIlluminaHeader {
+    read_name: String,
+    instrument: String,
+    run_number: u32,
+    flowcell: String,
+    lane: u32,
+    tile: u32,
+    x: u32,
+    y: u32,
+    umi: Option<String>,
+    read_number: Option<u8>,
+    filtered: Option<bool>,
+    control_number: Option<u32>,
+    index: Option<String>,
+}
+
+impl FastqIlluminaHeader {
+    fn parse(name: &str, comment: &str) -> Option<Self> {
+        let fields = name.split(':').collect::<Vec<_>>();
+        if !(7..=8).contains(&fields.len()) {
+            return None;
+        }
+
+        let mut header = Self {
+            read_name: name.to_string(),
+            instrument: fields[0].to_string(),
+            run_number: fields[1].parse().ok()?,
+            flowcell: fields[2].to_string(),
+            lane: fields[3].parse().ok()?,
+            tile: fields[4].parse().ok()?,
+            x: fields[5].parse().ok()?,
+            y: fields[6].parse().ok()?,
+            umi: fields.get(7).map(|umi| umi.to_string()),
+            read_number: None,
+            filtered: None,
+            control_number: None,
+            index: None,
+        };
+
+        let comment = comment.split_whitespace().next().unwrap_or_default();
+        if let [read_number, filtered, control_number, index] =
+            comment.split(':').collect::<Vec<_>>()[..]
+        {
+            header.read_number = read_number.parse().ok();
+            header.filtered = match filtered {
+                "Y" => Some(true),
+                "N" => Some(false),
+                _ => None,
+            };
+            header.control_number = control_number.parse().ok();
+            header.index = Some(index.to_string());
+        }
+
+        Some(header)
+    }
+
+    pub fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    pub fn run_number(&self) -> u32 {
+        self.run_number
+    }
+
+    pub fn flowcell(&self) -> &str {
+        &self.flowcell
+    }
+
+    pub fn lane(&self) -> u32 {
+        self.lane
+    }
+
+    pub fn tile(&self) -> u32 {
+        self.tile
+    }
+
+    /// X coordinate of the cluster within the tile.
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Y coordinate of the cluster within the tile.
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// Unique molecular identifier appended by bcl2fastq.
+    pub fn umi(&self) -> Option<&str> {
+        self.umi.as_deref()
+    }
+
+    pub fn read_number(&self) -> Option<u8> {
+        self.read_number
+    }
+
+    /// Whether the read failed the chastity filter (`Y`).
+    pub fn is_filtered(&self) -> Option<bool> {
+        self.filtered
+    }
+
+    /// Control bits, 0 if none are set.
+    pub fn control_number(&self) -> Option<u32> {
+        self.control_number
+    }
+
+    /// Sample index sequence or number, with dual indices joined by `+`. Empty indices are
+    /// returned as `None`.
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_deref().filter(|index| !index.is_empty())
+    }
+}
+
+/// Read name with a `/1` or `/2` mate suffix, optionally preceded by a `#` index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastqPairedHeader {
+    read_name: String,
+    index: Option<String>,
+    read_number: u8,
+}
+
+impl FastqPairedHeader {
+    fn parse(name: &str) -> Option<Self> {
+        let (read_name, read_number) = name.rsplit_once('/')?;
+        let read_number = read_number.parse().ok().filter(|number| *number > 0)?;
+        let (read_name, index) = match read_name.rsplit_once('#') {
+            Some((read_name, index)) => (read_name, Some(index.to_string())),
+            None => (read_name, None),
+        };
+
+        Some(Self {
+            read_name: read_name.to_string(),
+            index,
+            read_number,
+        })
+    }
+
+    /// Index following `#`, where `0` means that the run was not multiplexed.
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_deref()
+    }
+
+    pub fn read_number(&self) -> u8 {
+        self.read_number
+    }
+}
+
+/// Header written by the Oxford Nanopore basecallers. The typed fields are `None` if their
+/// key is missing or its value is malformed; all pairs remain available through
+/// [`Self::get`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastqNanoporeHeader {
+    read_id: String,
+    fields: Vec<(String, String)>,
+}
+
+impl FastqNanoporeHeader {
+    const KEYS: &'static [&'static str] = &["runid", "read", "ch", "start_time", "barcode"];
+
+    fn parse(name: &str, comment: &str) -> Option<Self> {
+        let fields = comment
+            .split_whitespace()
+            .filter_map(|field| field.split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        if !fields
+            .iter()
+            .any(|(key, _)| Self::KEYS.contains(&key.as_str()))
+        {
+            return None;
+        }
+
+        Some(Self {
+            read_id: name.to_string(),
+            fields,
+        })
+    }
+
+    pub fn read_id(&self) -> &str {
+        &self.read_id
+    }
+
+    /// Value of any `key=value` pair, e.g. `flow_cell_id` or `basecall_model_version_id`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn run_id(&self) -> Option<&str> {
+        self.get("runid")
+    }
+
+    /// Number of the read on its channel.
+    pub fn read(&self) -> Option<u64> {
+        self.get("read")?.parse().ok()
+    }
+
+    pub fn channel(&self) -> Option<u32> {
+        self.get("ch")?.parse().ok()
+    }
+
+    /// Start time in ISO 8601 format, as written by the basecaller.
+    pub fn start_time(&self) -> Option<&str> {
+        self.get("start_time")
+    }
+
+    /// Barcode assigned during demultiplexing, or `unclassified`.
+    pub fn barcode(&self) -> Option<&str> {
+        self.get("barcode")
+    }
+}
+
+impl FastqMetadata {
+    /// Parses [`Self::sequence_name`] according to the Illumina or Oxford Nanopore naming
+    /// conventions. The raw header remains available through [`Self::sequence_name`].
+    pub fn parsed_header(&self) -> FastqHeader {
+        FastqHeader::from(self.sequence_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::Fastq;
+
+    #[test]
+    fn test_fastq_parsed_header() {
+        let source = indoc! {"
+            @EAS139:136:FC706VJ:2:2104:15343:197393:ACGTACGT 1:Y:18:ATCACG+GCTAGC
+            ACGT
+            +
+            IIII
+            @EAS139:136:FC706VJ:2:2104:15343:197393
+            ACGT
+            +
+            IIII
+            @HWUSI-EAS100R:6:73:941:1973#0/2
+            ACGT
+            +
+            IIII
+            @0ab5f1b4 runid=a8f2 sampleid=S1 read=17 ch=489 start_time=2019-06-17T08:41:27Z barcode=barcode01
+            ACGT
+            +
+            IIII
+            @Rosalind_6404 sample 1
+            ACGT
+            +
+            IIII
+        "};
+        let fastq = Fastq::parse(source).unwrap();
+        let headers = fastq
+            .sequences_iter()
+            .map(|sequence| sequence.metadata().parsed_header())
+            .collect::<Vec<_>>();
+
+        let FastqHeader::Illumina(illumina) = &headers[0] else {
+            panic!("expected an Illumina header: {:?}", headers[0]);
+        };
+        assert_eq!(illumina.instrument(), "EAS139");
+        assert_eq!(illumina.run_number(), 136);
+        assert_eq!(illumina.flowcell(), "FC706VJ");
+        assert_eq!(
+            (illumina.lane(), illumina.tile(), illumina.x(), illumina.y()),
+            (2, 2104, 15343, 197393)
+        );
+        assert_eq!(illumina.umi(), Some("ACGTACGT"));
+        assert_eq!(illumina.is_filtered(), Some(true));
+        assert_eq!(illumina.control_number(), Some(18));
+        assert_eq!(headers[0].read_number(), Some(1));
+        assert_eq!(headers[0].barcode(), Some("ATCACG+GCTAGC"));
+
+        assert!(matches!(headers[1], FastqHeader::Illumina(_)));
+        assert_eq!(
+            headers[1].read_name(),
+            "EAS139:136:FC706VJ:2:2104:15343:197393"
+        );
+        assert_eq!(headers[1].read_number(), None);
+        assert_eq!(headers[1].barcode(), None);
+
+        assert!(matches!(headers[2], FastqHeader::Paired(_)));
+        assert_eq!(headers[2].read_name(), "HWUSI-EAS100R:6:73:941:1973");
+        assert_eq!(headers[2].read_number(), Some(2));
+        assert_eq!(headers[2].barcode(), None);
+
+        let indexed = FastqHeader::from("HWUSI-EAS100R:6:73:941:1973#ATCACG/1");
+        assert_eq!(indexed.read_name(), "HWUSI-EAS100R:6:73:941:1973");
+        assert_eq!(indexed.barcode(), Some("ATCACG"));
+
+        let FastqHeader::Nanopore(nanopore) = &headers[3] else {
+            panic!("expected a Nanopore header: {:?}", headers[3]);
+        };
+        assert_eq!(nanopore.read_id(), "0ab5f1b4");
+        assert_eq!(nanopore.run_id(), Some("a8f2"));
+        assert_eq!(nanopore.read(), Some(17));
+        assert_eq!(nanopore.channel(), Some(489));
+        assert_eq!(nanopore.start_time(), Some("2019-06-17T08:41:27Z"));
+        assert_eq!(nanopore.get("sampleid"), Some("S1"));
+        assert_eq!(headers[3].barcode(), Some("barcode01"));
+
+        assert_eq!(headers[4], FastqHeader::Other("Rosalind_6404".to_string()));
+        assert_eq!(
+            fastq.sequences_iter().nth(4).unwrap().sequence_name(),
+            "Rosalind_6404 sample 1"
+        );
+    }
+}
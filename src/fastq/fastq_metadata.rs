@@ -1,14 +1,23 @@
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FastqMetadata {
     sequence_header: String,
+    quality_header: Option<String>,
 }
 
 impl FastqMetadata {
-    pub(crate) fn new(sequence_header: String) -> Self {
-        Self { sequence_header }
+    pub(crate) fn new(sequence_header: String, quality_header: Option<String>) -> Self {
+        Self {
+            sequence_header,
+            quality_header,
+        }
     }
 
     pub fn sequence_name(&self) -> &str {
         &self.sequence_header
     }
+
+    /// The text following `+` on the quality header line, if any.
+    pub fn quality_header(&self) -> Option<&str> {
+        self.quality_header.as_deref()
+    }
 }
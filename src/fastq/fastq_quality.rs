@@ -0,0 +1,218 @@
+use super::{Fastq, FastqSequence};
+
+/// ASCII encoding of the quality line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FastqQualityEncoding {
+    /// Phred+33, used by Sanger, Illumina 1.8+, PacBio and Oxford Nanopore.
+    #[default]
+    Sanger,
+    /// Phred+64, used by Illumina 1.3 to 1.7.
+    Illumina13,
+    /// Solexa+64, used by Solexa and Illumina before 1.3. Scores start at -5 and follow a
+    /// log-odds scale instead of the Phred scale.
+    Solexa,
+}
+
+impl FastqQualityEncoding {
+    /// ASCII code of the score 0.
+    pub fn offset(&self) -> u8 {
+        match self {
+            FastqQualityEncoding::Sanger => b'!',
+            FastqQualityEncoding::Illumina13 | FastqQualityEncoding::Solexa => b'@',
+        }
+    }
+
+    /// Guesses the encoding from the range of quality characters, or returns `None` if there
+    /// are no characters or some are outside of `!` to `~`.
+    ///
+    /// Characters below `;` only occur in Phred+33 and characters from `;` to `?` only in
+    /// Solexa+64. If all characters are `@` or above, characters beyond `K` indicate Phred+64;
+    /// otherwise Phred+33 is assumed, as used by current instruments. Pass the qualities of
+    /// as many records as possible, since a single high-quality read is ambiguous.
+    pub fn detect<'a>(qualities: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        let mut range: Option<(u8, u8)> = None;
+        for quality in qualities {
+            for byte in quality.bytes() {
+                if !(b'!'..=b'~').contains(&byte) {
+                    return None;
+                }
+                range =
+                    Some(range.map_or((byte, byte), |(min, max)| (min.min(byte), max.max(byte))));
+            }
+        }
+
+        let (min, max) = range?;
+        Some(if min < b';' {
+            FastqQualityEncoding::Sanger
+        } else if min < b'@' {
+            FastqQualityEncoding::Solexa
+        } else if max > b'K' {
+            FastqQualityEncoding::Illumina13
+        } else {
+            FastqQualityEncoding::Sanger
+        })
+    }
+
+    /// Phred scores of the quality characters. Solexa scores are converted to the Phred scale
+    /// and rounded.
+    pub fn decode(&self, quality: &str) -> Vec<u8> {
+        quality
+            .bytes()
+            .map(|byte| {
+                let score = i16::from(byte) - i16::from(self.offset());
+                match self {
+                    FastqQualityEncoding::Solexa => solexa_to_phred(score),
+                    _ => score.clamp(0, u8::MAX.into()) as u8,
+                }
+            })
+            .collect()
+    }
+
+    /// Probabilities that the base calls are wrong.
+    pub fn error_probabilities(&self, quality: &str) -> Vec<f64> {
+        quality
+            .bytes()
+            .map(|byte| {
+                let score = f64::from(i16::from(byte) - i16::from(self.offset()));
+                let odds = 10f64.powf(-score / 10.0);
+                match self {
+                    FastqQualityEncoding::Solexa => odds / (1.0 + odds),
+                    _ => odds.min(1.0),
+                }
+            })
+            .collect()
+    }
+
+    /// Quality characters for the Phred scores, clamped to the range of the encoding.
+    pub fn encode(&self, scores: &[u8]) -> String {
+        scores
+            .iter()
+            .map(|&score| {
+                let score = match self {
+                    FastqQualityEncoding::Solexa => phred_to_solexa(score),
+                    _ => score.into(),
+                };
+                let byte = (score + i16::from(self.offset())).clamp(b'!'.into(), b'~'.into());
+                char::from(byte as u8)
+            })
+            .collect()
+    }
+
+    /// Re-encodes a quality line from this encoding to another one.
+    pub fn convert(&self, quality: &str, encoding: FastqQualityEncoding) -> String {
+        if *self == encoding {
+            return quality.to_string();
+        }
+        encoding.encode(&self.decode(quality))
+    }
+}
+
+fn solexa_to_phred(score: i16) -> u8 {
+    let phred = 10.0 * (10f64.powf(f64::from(score) / 10.0) + 1.0).log10();
+    phred.round() as u8
+}
+
+fn phred_to_solexa(score: u8) -> i16 {
+    if score == 0 {
+        return -5;
+    }
+    let solexa = 10.0 * (10f64.powf(f64::from(score) / 10.0) - 1.0).log10();
+    (solexa.round() as i16).max(-5)
+}
+
+impl FastqSequence {
+    /// Guesses the quality encoding from this record alone, see
+    /// [`FastqQualityEncoding::detect`].
+    pub fn quality_encoding(&self) -> Option<FastqQualityEncoding> {
+        FastqQualityEncoding::detect([self.quality_str()])
+    }
+
+    /// Phred scores of the quality line in the given encoding.
+    pub fn quality_scores(&self, encoding: FastqQualityEncoding) -> Vec<u8> {
+        encoding.decode(self.quality_str())
+    }
+
+    /// Probabilities that the base calls are wrong, given the quality encoding.
+    pub fn error_probabilities(&self, encoding: FastqQualityEncoding) -> Vec<f64> {
+        encoding.error_probabilities(self.quality_str())
+    }
+
+    /// Re-encodes the quality line, e.g. to normalize Illumina 1.3+ output to Phred+33.
+    pub fn convert_quality(&mut self, from: FastqQualityEncoding, to: FastqQualityEncoding) {
+        let quality = from.convert(self.quality_str(), to);
+        self.set_quality(quality);
+    }
+}
+
+impl Fastq {
+    /// Guesses the quality encoding from the qualities of all records, see
+    /// [`FastqQualityEncoding::detect`].
+    pub fn quality_encoding(&self) -> Option<FastqQualityEncoding> {
+        FastqQualityEncoding::detect(self.sequences_iter().map(FastqSequence::quality_str))
+    }
+
+    /// Re-encodes the quality lines of all records to the given encoding, detecting the
+    /// current one with [`Self::quality_encoding`]. Returns the detected encoding.
+    pub fn convert_quality(
+        &mut self,
+        encoding: FastqQualityEncoding,
+    ) -> Option<FastqQualityEncoding> {
+        let detected = self.quality_encoding()?;
+        for sequence in self.sequences_iter_mut() {
+            sequence.convert_quality(detected, encoding);
+        }
+        Some(detected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_fastq_quality_encoding() {
+        use FastqQualityEncoding::*;
+
+        assert_eq!(FastqQualityEncoding::detect(["!''*((II"]), Some(Sanger));
+        assert_eq!(FastqQualityEncoding::detect([";;>@BCh"]), Some(Solexa));
+        assert_eq!(FastqQualityEncoding::detect(["BBBBhhfa"]), Some(Illumina13));
+        assert_eq!(FastqQualityEncoding::detect(["FFFF:FF"]), Some(Sanger));
+        assert_eq!(FastqQualityEncoding::detect(["FFFF", "#,,F"]), Some(Sanger));
+        assert_eq!(FastqQualityEncoding::detect([""]), None);
+        assert_eq!(FastqQualityEncoding::detect(["II I"]), None);
+
+        assert_eq!(Sanger.decode("!+5?I"), [0, 10, 20, 30, 40]);
+        assert_eq!(Illumina13.decode("@J^h"), [0, 10, 30, 40]);
+        assert_eq!(Solexa.decode(";@J^h"), [1, 3, 10, 30, 40]);
+        assert_eq!(Sanger.encode(&[0, 10, 40, 100]), "!+I~");
+        assert_eq!(Solexa.encode(&[0, 1, 3, 10, 40]), ";;@Jh");
+
+        let probabilities = Sanger.error_probabilities("!+5");
+        assert_eq!(probabilities[0], 1.0);
+        assert!((probabilities[1] - 0.1).abs() < 1e-12);
+        assert!((probabilities[2] - 0.01).abs() < 1e-12);
+        assert!((Solexa.error_probabilities("@")[0] - 0.5).abs() < 1e-12);
+
+        assert_eq!(Illumina13.convert("@J^h", Sanger), "!+?I");
+        assert_eq!(Sanger.convert("!+?I", Illumina13), "@J^h");
+
+        let source = indoc! {"
+            @read_1
+            ACGTACGT
+            +
+            hhhhJJ^B
+            @read_2
+            ACGT
+            +
+            hhBB
+        "};
+        let mut fastq = Fastq::parse(source).unwrap();
+        assert_eq!(fastq.convert_quality(Sanger), Some(Illumina13));
+        let sequences = fastq.sequences_iter().collect::<Vec<_>>();
+        assert_eq!(sequences[0].quality_str(), "IIII++?#");
+        assert_eq!(sequences[1].quality_scores(Sanger), [40, 40, 2, 2]);
+        assert_eq!(fastq.quality_encoding(), Some(Sanger));
+    }
+}
@@ -0,0 +1,137 @@
+use std::io::{self, BufRead};
+
+use crate::helper::LineReader;
+
+use super::{Fastq, FastqSequence};
+
+/// Reads FASTQ sequences one at a time from a [`BufRead`] source.
+///
+/// Only a single record is held in memory at a time. Record boundaries are determined by
+/// reading quality lines until they cover the sequence length, and each record is then
+/// parsed on its own. A line starting with `@` that would overrun the sequence length is
+/// taken as the next header. The reader stops after an I/O error or a length mismatch, as
+/// the following record boundaries can no longer be trusted.
+#[derive(Debug)]
+pub struct FastqReader<R> {
+    lines: LineReader<R>,
+    failed: bool,
+}
+
+impl<R: BufRead> FastqReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: LineReader::new(reader),
+            failed: false,
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<String>> {
+        let mut record = String::new();
+        if !self.lines.append_line(&mut record)? {
+            return Ok(None);
+        }
+
+        let mut sequence_length = 0;
+        while let Some(line) = self.lines.peek()? {
+            if line.starts_with('+') {
+                break;
+            }
+            sequence_length += line.trim_end().len();
+            self.lines.append_line(&mut record)?;
+        }
+
+        self.lines.append_line(&mut record)?;
+
+        let mut quality_length = 0;
+        while quality_length < sequence_length {
+            let Some(line) = self.lines.peek()? else {
+                break;
+            };
+            let length = line.trim_end().len();
+            if line.starts_with('@') && quality_length + length > sequence_length {
+                break;
+            }
+            quality_length += length;
+            self.lines.append_line(&mut record)?;
+        }
+
+        self.lines
+            .append_lines_while(&mut record, |line| line.trim().is_empty())?;
+
+        Ok(Some(record))
+    }
+}
+
+impl<R: BufRead> Iterator for FastqReader<R> {
+    type Item = crate::Result<FastqSequence>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.read_record() {
+            Ok(record) => record.map(|record| {
+                let result = Fastq::parse_record(&record);
+                if let Err(crate::Error::FastqError(err)) = &result {
+                    self.failed = matches!(**err, super::Error::FastqLengthMismatch { .. });
+                }
+                result
+            }),
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_fastq_reader() {
+        let source = indoc! {"
+            @Rosalind_6404
+            CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCT
+            CTGAGGCTTCCGGCCTTCCCTCCCACTAATAATTCTGAGG
+            +Rosalind_6404
+            !''*((((***+))%%%++)(%%%%).1***-+*''))**
+            55CCF>>>>>>CCCCCCC65IIIIIIIIIIIIIIIIIIII
+            @Rosalind_5959
+            CCATCGGTAGCGCATCCTTAGTCCAATTAAGTCCCTATCC
+            +
+            @'''))***(AAAAAAABBBBBBCCCCCCCCCCCCCCCCC
+
+        "};
+        let expected = Fastq::parse(source).unwrap();
+        let streamed = FastqReader::new(source.as_bytes())
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(streamed.len(), 2);
+        for (expected, streamed) in expected.sequences_iter().zip(&streamed) {
+            assert_eq!(expected.sequence_name(), streamed.sequence_name());
+            assert_eq!(expected.sequence_str(), streamed.sequence_str());
+            assert_eq!(expected.quality_str(), streamed.quality_str());
+        }
+
+        let source = "@read_1\nACGTACGT\n+\nIIIIIII\n@read_2\nACGT\n+\nIIII\n";
+        let mut reader = FastqReader::new(source.as_bytes());
+        match reader.next() {
+            Some(Err(crate::Error::FastqError(err))) => assert!(matches!(
+                *err,
+                crate::fastq::Error::FastqLengthMismatch {
+                    sequence_length: 8,
+                    quality_length: 7,
+                    ..
+                }
+            )),
+            other => panic!("expected a length mismatch, got {other:?}"),
+        }
+        assert!(reader.next().is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use super::FastqMetadata;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FastqSequence {
     metadata: FastqMetadata,
     sequence: String,
@@ -16,6 +16,10 @@ impl FastqSequence {
         }
     }
 
+    pub fn metadata(&self) -> &FastqMetadata {
+        &self.metadata
+    }
+
     pub fn sequence_name(&self) -> &str {
         self.metadata.sequence_name()
     }
@@ -28,3 +32,9 @@ impl FastqSequence {
         &self.quality
     }
 }
+
+impl FastqSequence {
+    pub(crate) fn set_quality(&mut self, quality: String) {
+        self.quality = quality;
+    }
+}
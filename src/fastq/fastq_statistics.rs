@@ -0,0 +1,704 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+};
+
+use super::{FastqQualityEncoding, FastqSequence};
+
+/// Highest Phred score that can be encoded in Phred+33.
+const MAX_SCORE: usize = (b'~' - b'!') as usize;
+
+/// Reads longer than this are tracked for duplication by their first
+/// [`DUPLICATION_PREFIX`] bases only.
+const DUPLICATION_MAX_LENGTH: usize = 75;
+const DUPLICATION_PREFIX: usize = 50;
+
+/// Number of leading positions with a bin of their own, see [`position_bin`].
+const EXACT_POSITIONS: usize = 256;
+
+/// Collects FastQC-style quality control statistics over FASTQ records.
+///
+/// Records are added one at a time with [`Self::add`], or from any iterator such as
+/// [`crate::Fastq::sequences_iter`] with [`Extend`]. [`Self::try_extend`] consumes a
+/// [`super::FastqReader`] directly, so a file can be summarized without holding it in memory.
+///
+/// Duplication is measured on exact sequences, except that reads longer than 75 bases are
+/// keyed on their first 50 bases, as in FastQC. To bound memory, only the first distinct keys
+/// up to [`Self::max_tracked_sequences`] are counted; reads with other keys seen afterwards are
+/// left out of the duplication statistics.
+///
+/// Like FastQC, the per-position statistics group the positions of long reads: the first 256
+/// positions are kept apart, then bins double in width every 128 bins. A megabase read thus
+/// spans under 2,000 bins.
+#[derive(Debug, Clone)]
+pub struct FastqStatistics {
+    quality_encoding: FastqQualityEncoding,
+    max_tracked_sequences: usize,
+    overrepresented_fraction: f64,
+
+    read_count: u64,
+    total_bases: u64,
+    gc_bases: u64,
+    n_bases: u64,
+    lengths: BTreeMap<usize, u64>,
+    /// Histogram of the Phred scores in each position bin.
+    position_scores: Vec<[u64; MAX_SCORE + 1]>,
+    position_n: Vec<u64>,
+    mean_qualities: BTreeMap<u8, u64>,
+    gc_percentages: BTreeMap<u8, u64>,
+    sequences: HashMap<String, u64>,
+    tracked_reads: u64,
+}
+
+impl Default for FastqStatistics {
+    fn default() -> Self {
+        Self {
+            quality_encoding: FastqQualityEncoding::default(),
+            max_tracked_sequences: 100_000,
+            overrepresented_fraction: 0.001,
+            read_count: 0,
+            total_bases: 0,
+            gc_bases: 0,
+            n_bases: 0,
+            lengths: BTreeMap::new(),
+            position_scores: Vec::new(),
+            position_n: Vec::new(),
+            mean_qualities: BTreeMap::new(),
+            gc_percentages: BTreeMap::new(),
+            sequences: HashMap::new(),
+            tracked_reads: 0,
+        }
+    }
+}
+
+impl FastqStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encoding used to decode the quality lines, Phred+33 by default.
+    pub fn quality_encoding(mut self, quality_encoding: FastqQualityEncoding) -> Self {
+        self.quality_encoding = quality_encoding;
+        self
+    }
+
+    /// Number of distinct sequences counted for duplication, 100,000 by default.
+    pub fn max_tracked_sequences(mut self, max_tracked_sequences: usize) -> Self {
+        self.max_tracked_sequences = max_tracked_sequences;
+        self
+    }
+
+    /// Fraction of the tracked reads above which a sequence is reported as overrepresented,
+    /// 0.001 by default.
+    pub fn overrepresented_fraction(mut self, overrepresented_fraction: f64) -> Self {
+        self.overrepresented_fraction = overrepresented_fraction;
+        self
+    }
+}
+
+impl FastqStatistics {
+    pub fn add(&mut self, sequence: &FastqSequence) {
+        let bases = sequence.sequence_str().as_bytes();
+        let scores = self.quality_encoding.decode(sequence.quality_str());
+
+        self.read_count += 1;
+        self.total_bases += bases.len() as u64;
+        *self.lengths.entry(bases.len()).or_default() += 1;
+
+        let bins = bases
+            .len()
+            .checked_sub(1)
+            .map_or(0, |last| position_bin(last) + 1);
+        if self.position_scores.len() < bins {
+            self.position_scores.resize(bins, [0; MAX_SCORE + 1]);
+            self.position_n.resize(bins, 0);
+        }
+        for (position, score) in scores.iter().enumerate() {
+            self.position_scores[position_bin(position)][usize::from(*score).min(MAX_SCORE)] += 1;
+        }
+
+        let mut gc = 0;
+        for (position, base) in bases.iter().enumerate() {
+            match base.to_ascii_uppercase() {
+                b'G' | b'C' => gc += 1,
+                b'N' => {
+                    self.position_n[position_bin(position)] += 1;
+                    self.n_bases += 1;
+                }
+                _ => {}
+            }
+        }
+        self.gc_bases += gc as u64;
+
+        if !bases.is_empty() {
+            let sum = scores
+                .iter()
+                .map(|score| usize::from(*score))
+                .sum::<usize>();
+            let mean = (sum as f64 / scores.len() as f64).round() as u8;
+            *self.mean_qualities.entry(mean).or_default() += 1;
+
+            let percentage = (gc as f64 * 100.0 / bases.len() as f64).round() as u8;
+            *self.gc_percentages.entry(percentage).or_default() += 1;
+        }
+
+        let mut key = sequence.sequence_str();
+        if key.len() > DUPLICATION_MAX_LENGTH {
+            key = key.get(..DUPLICATION_PREFIX).unwrap_or(key);
+        }
+        if let Some(count) = self.sequences.get_mut(key) {
+            *count += 1;
+            self.tracked_reads += 1;
+        } else if self.sequences.len() < self.max_tracked_sequences {
+            self.sequences.insert(key.to_string(), 1);
+            self.tracked_reads += 1;
+        }
+    }
+
+    /// Adds the records of a fallible iterator such as [`super::FastqReader`], stopping at the
+    /// first error. Records read before the error remain counted.
+    pub fn try_extend<T: IntoIterator<Item = crate::Result<FastqSequence>>>(
+        &mut self,
+        iter: T,
+    ) -> crate::Result<()> {
+        for sequence in iter {
+            self.add(&sequence?);
+        }
+        Ok(())
+    }
+
+    pub fn summary(&self) -> FastqSummary {
+        let max_length = self.lengths.keys().next_back().copied().unwrap_or(0);
+        let position_bins = (0..self.position_scores.len())
+            .map(|bin| {
+                let range = position_bin_range(bin);
+                range.start..range.end.min(max_length)
+            })
+            .collect();
+
+        let position_quality = self
+            .position_scores
+            .iter()
+            .map(FastqQualityQuantiles::from_histogram)
+            .collect();
+
+        let position_n = self
+            .position_n
+            .iter()
+            .zip(&self.position_scores)
+            .map(|(n, scores)| fraction(*n, scores.iter().sum()))
+            .collect();
+
+        let mut overrepresented = self
+            .sequences
+            .iter()
+            .filter(|(_, count)| {
+                **count > 1 && fraction(**count, self.tracked_reads) > self.overrepresented_fraction
+            })
+            .map(|(sequence, count)| FastqOverrepresentedSequence {
+                sequence: sequence.clone(),
+                count: *count,
+                fraction: fraction(*count, self.tracked_reads),
+            })
+            .collect::<Vec<_>>();
+        overrepresented.sort_by(|a, b| b.count.cmp(&a.count).then(a.sequence.cmp(&b.sequence)));
+
+        let mut duplication_levels = BTreeMap::<u64, u64>::new();
+        for count in self.sequences.values() {
+            *duplication_levels.entry(*count).or_default() += 1;
+        }
+
+        FastqSummary {
+            read_count: self.read_count,
+            total_bases: self.total_bases,
+            gc_content: fraction(self.gc_bases, self.total_bases),
+            n_content: fraction(self.n_bases, self.total_bases),
+            length_distribution: self.lengths.iter().map(|(k, v)| (*k, *v)).collect(),
+            position_bins,
+            position_quality,
+            mean_quality_distribution: self.mean_qualities.iter().map(|(k, v)| (*k, *v)).collect(),
+            gc_distribution: self.gc_percentages.iter().map(|(k, v)| (*k, *v)).collect(),
+            position_n,
+            overrepresented,
+            duplication_levels: duplication_levels.into_iter().collect(),
+            deduplicated_fraction: fraction(self.sequences.len() as u64, self.tracked_reads),
+        }
+    }
+}
+
+impl<'a> Extend<&'a FastqSequence> for FastqStatistics {
+    fn extend<T: IntoIterator<Item = &'a FastqSequence>>(&mut self, iter: T) {
+        for sequence in iter {
+            self.add(sequence);
+        }
+    }
+}
+
+impl Extend<FastqSequence> for FastqStatistics {
+    fn extend<T: IntoIterator<Item = FastqSequence>>(&mut self, iter: T) {
+        for sequence in iter {
+            self.add(&sequence);
+        }
+    }
+}
+
+/// Index of the bin holding a 0-based position. Past [`EXACT_POSITIONS`], each level of
+/// `EXACT_POSITIONS / 2` bins covers as many positions as all the previous ones.
+fn position_bin(position: usize) -> usize {
+    if position < EXACT_POSITIONS {
+        return position;
+    }
+    let level = (position / EXACT_POSITIONS).ilog2() as usize;
+    let start = EXACT_POSITIONS << level;
+    EXACT_POSITIONS + level * EXACT_POSITIONS / 2 + ((position - start) >> (level + 1))
+}
+
+/// Positions covered by a bin, the inverse of [`position_bin`].
+fn position_bin_range(bin: usize) -> Range<usize> {
+    if bin < EXACT_POSITIONS {
+        return bin..bin + 1;
+    }
+    let level = (bin - EXACT_POSITIONS) / (EXACT_POSITIONS / 2);
+    let width = 2 << level;
+    let start =
+        (EXACT_POSITIONS << level) + (bin - EXACT_POSITIONS) % (EXACT_POSITIONS / 2) * width;
+    start..start + width
+}
+
+fn fraction(count: u64, total: u64) -> f64 {
+    match total {
+        0 => 0.0,
+        _ => count as f64 / total as f64,
+    }
+}
+
+/// Distribution of the Phred scores in one position bin of the reads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FastqQualityQuantiles {
+    mean: f64,
+    percentile_10: u8,
+    lower_quartile: u8,
+    median: u8,
+    upper_quartile: u8,
+    percentile_90: u8,
+}
+
+impl FastqQualityQuantiles {
+    fn from_histogram(histogram: &[u64; MAX_SCORE + 1]) -> Self {
+        let total = histogram.iter().sum::<u64>();
+        let sum = histogram
+            .iter()
+            .enumerate()
+            .map(|(score, count)| score as u64 * count)
+            .sum::<u64>();
+
+        let quantile = |quantile: f64| {
+            let rank = ((quantile * total as f64).ceil() as u64).max(1);
+            let mut seen = 0;
+            histogram
+                .iter()
+                .position(|count| {
+                    seen += count;
+                    seen >= rank
+                })
+                .unwrap_or(0) as u8
+        };
+
+        Self {
+            mean: fraction(sum, total),
+            percentile_10: quantile(0.1),
+            lower_quartile: quantile(0.25),
+            median: quantile(0.5),
+            upper_quartile: quantile(0.75),
+            percentile_90: quantile(0.9),
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn percentile_10(&self) -> u8 {
+        self.percentile_10
+    }
+
+    pub fn lower_quartile(&self) -> u8 {
+        self.lower_quartile
+    }
+
+    pub fn median(&self) -> u8 {
+        self.median
+    }
+
+    pub fn upper_quartile(&self) -> u8 {
+        self.upper_quartile
+    }
+
+    pub fn percentile_90(&self) -> u8 {
+        self.percentile_90
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FastqOverrepresentedSequence {
+    sequence: String,
+    count: u64,
+    fraction: f64,
+}
+
+impl FastqOverrepresentedSequence {
+    /// The read sequence, or its first 50 bases for reads longer than 75 bases.
+    pub fn sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Share of the reads tracked for duplication.
+    pub fn fraction(&self) -> f64 {
+        self.fraction
+    }
+}
+
+/// Snapshot of [`FastqStatistics`], see [`Self::to_json`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FastqSummary {
+    read_count: u64,
+    total_bases: u64,
+    gc_content: f64,
+    n_content: f64,
+    length_distribution: Vec<(usize, u64)>,
+    position_bins: Vec<Range<usize>>,
+    position_quality: Vec<FastqQualityQuantiles>,
+    mean_quality_distribution: Vec<(u8, u64)>,
+    gc_distribution: Vec<(u8, u64)>,
+    position_n: Vec<f64>,
+    overrepresented: Vec<FastqOverrepresentedSequence>,
+    duplication_levels: Vec<(u64, u64)>,
+    deduplicated_fraction: f64,
+}
+
+impl FastqSummary {
+    pub fn read_count(&self) -> u64 {
+        self.read_count
+    }
+
+    pub fn total_bases(&self) -> u64 {
+        self.total_bases
+    }
+
+    /// Share of G and C among all bases.
+    pub fn gc_content(&self) -> f64 {
+        self.gc_content
+    }
+
+    /// Share of N among all bases.
+    pub fn n_content(&self) -> f64 {
+        self.n_content
+    }
+
+    /// Number of reads of each length, by ascending length.
+    pub fn length_distribution(&self) -> &[(usize, u64)] {
+        &self.length_distribution
+    }
+
+    /// 0-based positions grouped in each bin of [`Self::position_quality`] and
+    /// [`Self::position_n`]. Bins hold a single position up to 256 bases.
+    pub fn position_bins(&self) -> &[Range<usize>] {
+        &self.position_bins
+    }
+
+    /// Phred score distribution in each bin of [`Self::position_bins`].
+    pub fn position_quality(&self) -> &[FastqQualityQuantiles] {
+        &self.position_quality
+    }
+
+    /// Number of reads for each mean Phred score, rounded to an integer.
+    pub fn mean_quality_distribution(&self) -> &[(u8, u64)] {
+        &self.mean_quality_distribution
+    }
+
+    /// Number of reads for each GC percentage, rounded to an integer.
+    pub fn gc_distribution(&self) -> &[(u8, u64)] {
+        &self.gc_distribution
+    }
+
+    /// Share of N among the bases in each bin of [`Self::position_bins`].
+    pub fn position_n(&self) -> &[f64] {
+        &self.position_n
+    }
+
+    /// Sequences above the overrepresented fraction, most frequent first.
+    pub fn overrepresented(&self) -> &[FastqOverrepresentedSequence] {
+        &self.overrepresented
+    }
+
+    /// Number of distinct sequences for each number of copies.
+    pub fn duplication_levels(&self) -> &[(u64, u64)] {
+        &self.duplication_levels
+    }
+
+    /// Share of the tracked reads that would remain after removing exact duplicates.
+    pub fn deduplicated_fraction(&self) -> f64 {
+        self.deduplicated_fraction
+    }
+
+    /// Serializes the summary as a JSON object, with distributions as arrays of
+    /// `[value, count]` pairs.
+    pub fn to_json(&self) -> String {
+        let position_quality = json_array(self.position_quality.iter().map(|quantiles| {
+            json_object([
+                ("mean", json_number(quantiles.mean)),
+                ("percentile_10", quantiles.percentile_10.to_string()),
+                ("lower_quartile", quantiles.lower_quartile.to_string()),
+                ("median", quantiles.median.to_string()),
+                ("upper_quartile", quantiles.upper_quartile.to_string()),
+                ("percentile_90", quantiles.percentile_90.to_string()),
+            ])
+        }));
+        let overrepresented = json_array(self.overrepresented.iter().map(|sequence| {
+            json_object([
+                ("sequence", json_string(&sequence.sequence)),
+                ("count", sequence.count.to_string()),
+                ("fraction", json_number(sequence.fraction)),
+            ])
+        }));
+
+        json_object([
+            ("read_count", self.read_count.to_string()),
+            ("total_bases", self.total_bases.to_string()),
+            ("gc_content", json_number(self.gc_content)),
+            ("n_content", json_number(self.n_content)),
+            (
+                "length_distribution",
+                json_pairs(
+                    self.length_distribution
+                        .iter()
+                        .map(|(k, v)| (*k as u64, *v)),
+                ),
+            ),
+            (
+                "position_bins",
+                json_pairs(
+                    self.position_bins
+                        .iter()
+                        .map(|range| (range.start as u64, range.end as u64)),
+                ),
+            ),
+            ("position_quality", position_quality),
+            (
+                "mean_quality_distribution",
+                json_pairs(
+                    self.mean_quality_distribution
+                        .iter()
+                        .map(|(k, v)| (u64::from(*k), *v)),
+                ),
+            ),
+            (
+                "gc_distribution",
+                json_pairs(
+                    self.gc_distribution
+                        .iter()
+                        .map(|(k, v)| (u64::from(*k), *v)),
+                ),
+            ),
+            (
+                "position_n",
+                json_array(self.position_n.iter().map(|n| json_number(*n))),
+            ),
+            ("overrepresented", overrepresented),
+            (
+                "duplication_levels",
+                json_pairs(self.duplication_levels.iter().copied()),
+            ),
+            (
+                "deduplicated_fraction",
+                json_number(self.deduplicated_fraction),
+            ),
+        ])
+    }
+}
+
+fn json_object<const N: usize>(fields: [(&str, String); N]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{value}", json_string(key)))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Array of `[value, count]` pairs.
+fn json_pairs(pairs: impl Iterator<Item = (u64, u64)>) -> String {
+    json_array(pairs.map(|(value, count)| format!("[{value},{count}]")))
+}
+
+fn json_array(values: impl Iterator<Item = String>) -> String {
+    format!("[{}]", values.collect::<Vec<_>>().join(","))
+}
+
+/// JSON has no representation for NaN and infinities, which are written as `null`.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            char if u32::from(char) < 0x20 => {
+                json.push_str(&format!("\\u{:04x}", u32::from(char)));
+            }
+            char => json.push(char),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::{
+        fastq::{FastqMetadata, FastqReader},
+        Fastq,
+    };
+
+    #[test]
+    fn test_fastq_statistics() {
+        let source = indoc! {"
+            @read_1
+            ACGTNN
+            +
+            IIII##
+            @read_2
+            GGCC
+            +
+            5555
+            @read_3
+            GGCC
+            +
+            ++++
+        "};
+        let fastq = Fastq::parse(source).unwrap();
+        let mut statistics = FastqStatistics::new().overrepresented_fraction(0.5);
+        statistics.extend(fastq.sequences_iter());
+        let summary = statistics.summary();
+
+        assert_eq!(summary.read_count(), 3);
+        assert_eq!(summary.total_bases(), 14);
+        assert_eq!(summary.gc_content(), 10.0 / 14.0);
+        assert_eq!(summary.n_content(), 2.0 / 14.0);
+        assert_eq!(summary.length_distribution(), [(4, 2), (6, 1)]);
+        assert_eq!(
+            summary.mean_quality_distribution(),
+            [(10, 1), (20, 1), (27, 1)]
+        );
+        assert_eq!(summary.gc_distribution(), [(33, 1), (100, 2)]);
+        assert_eq!(summary.position_n(), [0.0, 0.0, 0.0, 0.0, 1.0, 1.0]);
+
+        let first = &summary.position_quality()[0];
+        assert!((first.mean() - 70.0 / 3.0).abs() < 1e-12);
+        assert_eq!(
+            (first.percentile_10(), first.median(), first.percentile_90()),
+            (10, 20, 40)
+        );
+        assert_eq!(summary.position_quality()[5].median(), 2);
+        assert_eq!(summary.position_bins()[5], 5..6);
+
+        assert_eq!(summary.overrepresented().len(), 1);
+        assert_eq!(summary.overrepresented()[0].sequence(), "GGCC");
+        assert_eq!(summary.overrepresented()[0].count(), 2);
+        assert_eq!(summary.duplication_levels(), [(1, 1), (2, 1)]);
+        assert_eq!(summary.deduplicated_fraction(), 2.0 / 3.0);
+
+        let mut streamed = FastqStatistics::new().overrepresented_fraction(0.5);
+        streamed
+            .try_extend(FastqReader::new(source.as_bytes()))
+            .unwrap();
+        assert_eq!(streamed.summary(), summary);
+
+        let mut truncated = FastqStatistics::new();
+        let result = truncated.try_extend(FastqReader::new(&source.as_bytes()[..58]));
+        assert!(result.is_err());
+        assert_eq!(truncated.summary().read_count(), 2);
+
+        let json = summary.to_json();
+        assert!(json.starts_with(r#"{"read_count":3,"total_bases":14,"#));
+        assert!(json.contains(r#""length_distribution":[[4,2],[6,1]],"#));
+        assert!(json.contains(r#""overrepresented":[{"sequence":"GGCC","count":2,"#));
+        assert!(json.ends_with(
+            r#""duplication_levels":[[1,1],[2,1]],"deduplicated_fraction":0.6666666666666666}"#
+        ));
+    }
+
+    #[test]
+    fn test_fastq_statistics_position_bins() {
+        let mut statistics = FastqStatistics::new();
+        statistics.add(&sequence(&"A".repeat(600)));
+        let summary = statistics.summary();
+
+        assert_eq!(summary.position_bins().len(), 256 + 128 + 22);
+        assert_eq!(summary.position_bins()[255], 255..256);
+        assert_eq!(summary.position_bins()[256], 256..258);
+        assert_eq!(summary.position_bins()[384], 512..516);
+        assert_eq!(summary.position_bins().last(), Some(&(596..600)));
+        assert_eq!(summary.position_quality()[400].mean(), 40.0);
+
+        for position in [0, 255, 256, 511, 512, 1_000_000] {
+            assert!(position_bin_range(position_bin(position)).contains(&position));
+        }
+        assert!(position_bin(1_000_000) < 2_000);
+    }
+
+    #[test]
+    fn test_fastq_statistics_json_values() {
+        assert_eq!(json_string("a\"b\\c\td\u{1}é"), r#""a\"b\\c\td\u0001é""#);
+        assert_eq!(json_number(0.5), "0.5");
+        assert_eq!(json_number(f64::NAN), "null");
+        assert_eq!(json_pairs([(1, 2), (3, 4)].into_iter()), "[[1,2],[3,4]]");
+        assert_eq!(
+            json_object([
+                ("a", "1".to_string()),
+                ("b", json_array(std::iter::empty()))
+            ]),
+            r#"{"a":1,"b":[]}"#
+        );
+    }
+
+    #[test]
+    fn test_fastq_statistics_duplication_prefix() {
+        let prefix = "ACGT".repeat(20);
+        let mut statistics = FastqStatistics::new().overrepresented_fraction(0.5);
+        for tail in ["A", "C", "GG"] {
+            statistics.add(&sequence(&format!("{prefix}{tail}")));
+        }
+        statistics.add(&sequence(&prefix[..75]));
+        let summary = statistics.summary();
+
+        assert_eq!(summary.duplication_levels(), [(1, 1), (3, 1)]);
+        assert_eq!(summary.overrepresented()[0].sequence(), &prefix[..50]);
+        assert_eq!(summary.overrepresented()[0].count(), 3);
+    }
+
+    fn sequence(bases: &str) -> FastqSequence {
+        FastqSequence::new(
+            FastqMetadata::new("read".to_string(), None),
+            bases.to_string(),
+            "I".repeat(bases.len()),
+        )
+    }
+}
@@ -0,0 +1,315 @@
+use super::{FastqQualityEncoding, FastqSequence};
+
+/// Trims FASTQ records by position, base quality and 3' adapters.
+///
+/// The steps run in a fixed order on the remaining bases: head and tail cropping, sliding
+/// window trimming, quality cutoff trimming, adapter removal and finally the minimum length
+/// filter. All steps are disabled by default.
+#[derive(Debug, Clone)]
+pub struct FastqTrimmer {
+    quality_encoding: FastqQualityEncoding,
+    crop_head: usize,
+    crop_tail: usize,
+    sliding_window: Option<(usize, u8)>,
+    quality_cutoff: Option<u8>,
+    adapters: Vec<String>,
+    adapter_mismatch_rate: f64,
+    min_adapter_overlap: usize,
+    min_length: usize,
+}
+
+impl Default for FastqTrimmer {
+    fn default() -> Self {
+        Self {
+            quality_encoding: FastqQualityEncoding::default(),
+            crop_head: 0,
+            crop_tail: 0,
+            sliding_window: None,
+            quality_cutoff: None,
+            adapters: Vec::new(),
+            adapter_mismatch_rate: 0.1,
+            min_adapter_overlap: 3,
+            min_length: 0,
+        }
+    }
+}
+
+impl FastqTrimmer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encoding used to decode the quality lines, Phred+33 by default.
+    pub fn quality_encoding(mut self, quality_encoding: FastqQualityEncoding) -> Self {
+        self.quality_encoding = quality_encoding;
+        self
+    }
+
+    /// Removes a fixed number of bases from the 5' end.
+    pub fn crop_head(mut self, crop_head: usize) -> Self {
+        self.crop_head = crop_head;
+        self
+    }
+
+    /// Removes a fixed number of bases from the 3' end.
+    pub fn crop_tail(mut self, crop_tail: usize) -> Self {
+        self.crop_tail = crop_tail;
+        self
+    }
+
+    /// Scans windows of the given size from the 5' end and cuts the read at the start of the
+    /// first window whose mean Phred score is below the threshold, like Trimmomatic's
+    /// `SLIDINGWINDOW`.
+    pub fn sliding_window(mut self, sliding_window: Option<(usize, u8)>) -> Self {
+        self.sliding_window = sliding_window.filter(|(window, _)| *window > 0);
+        self
+    }
+
+    /// Trims the 3' end with the algorithm of BWA and cutadapt: the cut maximizes the sum of
+    /// the cutoff minus the Phred score over the removed bases.
+    pub fn quality_cutoff(mut self, quality_cutoff: Option<u8>) -> Self {
+        self.quality_cutoff = quality_cutoff;
+        self
+    }
+
+    /// Adds a 3' adapter. The read is cut where the adapter, or a prefix of it at the very end
+    /// of the read, first aligns without gaps. `N` in the adapter matches any base. An empty
+    /// adapter never matches but still takes an index.
+    pub fn adapter(mut self, adapter: &str) -> Self {
+        self.adapters.push(adapter.to_ascii_uppercase());
+        self
+    }
+
+    /// Fraction of mismatches allowed within an adapter alignment, 0.1 by default.
+    pub fn adapter_mismatch_rate(mut self, adapter_mismatch_rate: f64) -> Self {
+        self.adapter_mismatch_rate = adapter_mismatch_rate.max(0.0);
+        self
+    }
+
+    /// Shortest adapter prefix removed at the 3' end of a read, 3 by default.
+    pub fn min_adapter_overlap(mut self, min_adapter_overlap: usize) -> Self {
+        self.min_adapter_overlap = min_adapter_overlap.max(1);
+        self
+    }
+
+    /// Discards reads shorter than the given length after trimming.
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+}
+
+impl FastqTrimmer {
+    pub fn trim(&self, sequence: &FastqSequence) -> FastqTrimmed {
+        let length = sequence.sequence_str().len();
+        let scores = self.quality_encoding.decode(sequence.quality_str());
+
+        let start = self.crop_head.min(length);
+        let mut end = length.saturating_sub(self.crop_tail).max(start);
+
+        if let Some((window, threshold)) = self.sliding_window {
+            end = sliding_window_end(&scores[start..end], window, threshold) + start;
+        }
+        if let Some(cutoff) = self.quality_cutoff {
+            end = quality_cutoff_end(&scores[start..end], cutoff) + start;
+        }
+
+        let mut adapter = None;
+        let bases = &sequence.sequence_str().as_bytes()[start..end];
+        for (index, candidate) in self.adapters.iter().enumerate() {
+            if candidate.is_empty() {
+                continue;
+            }
+            if let Some(position) = self.find_adapter(bases, candidate.as_bytes()) {
+                if start + position < end {
+                    end = start + position;
+                    adapter = Some(index);
+                }
+            }
+        }
+
+        FastqTrimmed {
+            sequence: FastqSequence::new(
+                sequence.metadata().clone(),
+                sequence.sequence_str()[start..end].to_string(),
+                sequence.quality_str()[start..end].to_string(),
+            ),
+            removed_head: start,
+            removed_tail: length - end,
+            adapter,
+            discarded: end - start < self.min_length,
+        }
+    }
+
+    /// Position of the first gapless alignment of the adapter, or of one of its prefixes
+    /// reaching the end of the read.
+    fn find_adapter(&self, bases: &[u8], adapter: &[u8]) -> Option<usize> {
+        (0..bases.len()).find(|&position| {
+            let overlap = adapter.len().min(bases.len() - position);
+            if overlap < self.min_adapter_overlap.min(adapter.len()) {
+                return false;
+            }
+
+            let allowed = (overlap as f64 * self.adapter_mismatch_rate).floor() as usize;
+            let mismatches = bases[position..position + overlap]
+                .iter()
+                .zip(adapter)
+                .filter(|(base, adapter)| **adapter != b'N' && !base.eq_ignore_ascii_case(adapter))
+                .count();
+            mismatches <= allowed
+        })
+    }
+}
+
+fn sliding_window_end(scores: &[u8], window: usize, threshold: u8) -> usize {
+    let threshold = usize::from(threshold) * window;
+    scores
+        .windows(window)
+        .position(|scores| {
+            scores
+                .iter()
+                .map(|score| usize::from(*score))
+                .sum::<usize>()
+                < threshold
+        })
+        .unwrap_or(scores.len())
+}
+
+fn quality_cutoff_end(scores: &[u8], cutoff: u8) -> usize {
+    let mut sum = 0;
+    let mut max = 0;
+    let mut end = scores.len();
+    for (index, score) in scores.iter().enumerate().rev() {
+        sum += i32::from(cutoff) - i32::from(*score);
+        if sum < 0 {
+            break;
+        }
+        if sum > max {
+            max = sum;
+            end = index;
+        }
+    }
+    end
+}
+
+/// Record produced by [`FastqTrimmer::trim`], along with the number of bases removed.
+#[derive(Debug, Clone)]
+pub struct FastqTrimmed {
+    sequence: FastqSequence,
+    removed_head: usize,
+    removed_tail: usize,
+    adapter: Option<usize>,
+    discarded: bool,
+}
+
+impl FastqTrimmed {
+    pub fn sequence(&self) -> &FastqSequence {
+        &self.sequence
+    }
+
+    /// The trimmed record, or `None` if it is shorter than the minimum length.
+    pub fn into_sequence(self) -> Option<FastqSequence> {
+        (!self.discarded).then_some(self.sequence)
+    }
+
+    /// Number of bases removed from the 5' end.
+    pub fn removed_head(&self) -> usize {
+        self.removed_head
+    }
+
+    /// Number of bases removed from the 3' end, including the adapter.
+    pub fn removed_tail(&self) -> usize {
+        self.removed_tail
+    }
+
+    pub fn removed_bases(&self) -> usize {
+        self.removed_head + self.removed_tail
+    }
+
+    /// Index of the adapter that was removed, in the order the adapters were added.
+    pub fn adapter(&self) -> Option<usize> {
+        self.adapter
+    }
+
+    /// Whether the record failed the minimum length filter.
+    pub fn is_discarded(&self) -> bool {
+        self.discarded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::Fastq;
+
+    #[test]
+    fn test_fastq_trimmer() {
+        let source = indoc! {"
+            @read_1
+            ACGTACGTACGTACGT
+            +
+            IIIIIIIIII5+'#'#
+            @read_2
+            TTTTCCCCAGATCGGAAG
+            +
+            IIIIIIIIIIIIIIIIII
+            @read_3
+            TTTTCCCCAGAT
+            +
+            IIIIIIIIIIII
+        "};
+        let fastq = Fastq::parse(source).unwrap();
+        let sequences = fastq.sequences_iter().collect::<Vec<_>>();
+
+        let trimmed = FastqTrimmer::new()
+            .crop_head(2)
+            .crop_tail(1)
+            .trim(sequences[0]);
+        assert_eq!(trimmed.sequence().sequence_str(), "GTACGTACGTACG");
+        assert_eq!(trimmed.sequence().quality_str(), "IIIIIIII5+'#'");
+        assert_eq!((trimmed.removed_head(), trimmed.removed_tail()), (2, 1));
+
+        let trimmed = FastqTrimmer::new()
+            .sliding_window(Some((4, 20)))
+            .trim(sequences[0]);
+        assert_eq!(trimmed.sequence().sequence_str(), "ACGTACGTA");
+        assert_eq!(trimmed.removed_bases(), 7);
+
+        let trimmed = FastqTrimmer::new()
+            .quality_cutoff(Some(10))
+            .trim(sequences[0]);
+        assert_eq!(trimmed.sequence().quality_str(), "IIIIIIIIII5+");
+        assert_eq!(trimmed.removed_tail(), 4);
+
+        let trimmer = FastqTrimmer::new()
+            .adapter("")
+            .adapter("GGGGGGGG")
+            .adapter("AGATCGGAAGAGC")
+            .min_length(8);
+        let trimmed = trimmer.trim(sequences[1]);
+        assert_eq!(trimmed.sequence().sequence_str(), "TTTTCCCC");
+        assert_eq!(trimmed.adapter(), Some(2));
+        assert!(!trimmed.is_discarded());
+
+        let trimmed = trimmer.trim(sequences[2]);
+        assert_eq!(trimmed.sequence().sequence_str(), "TTTTCCCC");
+        assert_eq!(trimmed.removed_tail(), 4);
+
+        let trimmed = FastqTrimmer::new()
+            .adapter("AGTTCGGAAG")
+            .min_length(10)
+            .trim(sequences[1]);
+        assert_eq!(trimmed.adapter(), Some(0));
+        assert!(trimmed.is_discarded());
+        assert!(trimmed.into_sequence().is_none());
+
+        let trimmed = FastqTrimmer::new()
+            .adapter("AGTTCGGAAG")
+            .adapter_mismatch_rate(0.0)
+            .trim(sequences[1]);
+        assert_eq!(trimmed.adapter(), None);
+        assert_eq!(trimmed.removed_bases(), 0);
+    }
+}
@@ -0,0 +1,172 @@
+use std::io::Write;
+
+use crate::{helper::wrap_lines, LineEnding};
+
+use super::{Fastq, FastqSequence};
+
+#[derive(Debug, Clone, Default)]
+pub struct FastqWriter {
+    line_width: Option<usize>,
+    line_ending: LineEnding,
+    repeat_sequence_name: bool,
+}
+
+impl FastqWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of sequence and quality characters per line.
+    /// `None` (the default, or `Some(0)`) writes strict 4-line records.
+    pub fn line_width(mut self, line_width: Option<usize>) -> Self {
+        self.line_width = line_width.filter(|width| *width > 0);
+        self
+    }
+
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Repeats the sequence name after `+` instead of leaving the quality header empty.
+    pub fn repeat_sequence_name(mut self, repeat_sequence_name: bool) -> Self {
+        self.repeat_sequence_name = repeat_sequence_name;
+        self
+    }
+}
+
+impl FastqWriter {
+    pub fn write<W: Write>(&self, writer: &mut W, fastq: &Fastq) -> crate::Result<()> {
+        for sequence in fastq.sequences_iter() {
+            self.write_sequence(writer, sequence)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_sequence<W: Write>(
+        &self,
+        writer: &mut W,
+        sequence: &FastqSequence,
+    ) -> crate::Result<()> {
+        let mut buffer = String::new();
+        self.format_sequence(&mut buffer, sequence)?;
+        writer.write_all(buffer.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn write_to_string(&self, fastq: &Fastq) -> crate::Result<String> {
+        let mut buffer = String::new();
+        for sequence in fastq.sequences_iter() {
+            self.format_sequence(&mut buffer, sequence)?;
+        }
+
+        Ok(buffer)
+    }
+
+    fn format_sequence(&self, buffer: &mut String, sequence: &FastqSequence) -> super::Result<()> {
+        let sequence_length = sequence.sequence_str().chars().count();
+        let quality_length = sequence.quality_str().chars().count();
+        if sequence_length != quality_length {
+            return Err(Box::new(super::Error::FastqLengthMismatch {
+                sequence_name: sequence.sequence_name().to_string(),
+                sequence_length,
+                quality_length,
+            }));
+        }
+
+        let line_ending = self.line_ending.as_str();
+
+        buffer.push('@');
+        buffer.push_str(sequence.sequence_name());
+        buffer.push_str(line_ending);
+
+        for line in wrap_lines(sequence.sequence_str(), self.line_width) {
+            buffer.push_str(line);
+            buffer.push_str(line_ending);
+        }
+
+        buffer.push('+');
+        if self.repeat_sequence_name {
+            buffer.push_str(sequence.sequence_name());
+        }
+        buffer.push_str(line_ending);
+
+        for line in wrap_lines(sequence.quality_str(), self.line_width) {
+            buffer.push_str(line);
+            buffer.push_str(line_ending);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::fastq::FastqMetadata;
+
+    #[test]
+    fn test_fastq_write() {
+        let source = indoc! {"
+            @Rosalind_6404
+            CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCT
+            +
+            !''*((((***+))%%%++)(%%%%).1***-+*''))**
+            @Rosalind_5959
+            CCATCGGTAGCGCATCCTTAGTCCAATTAAGTCCCTATCC
+            +
+            5'''))***(AAAAAAABBBBBBCCCCCCCCCCCCCCCCC
+        "};
+        let fastq = Fastq::parse(source).unwrap();
+        assert_eq!(FastqWriter::new().write_to_string(&fastq).unwrap(), source);
+
+        let written = FastqWriter::new()
+            .line_width(Some(16))
+            .repeat_sequence_name(true)
+            .write_to_string(&fastq)
+            .unwrap();
+        assert_eq!(
+            written.lines().take(8).collect::<Vec<_>>(),
+            [
+                "@Rosalind_6404",
+                "CCTGCGGAAGATCGGC",
+                "ACTAGAATAGCCAGAA",
+                "CCGTTTCT",
+                "+Rosalind_6404",
+                "!''*((((***+))%%",
+                "%++)(%%%%).1***-",
+                "+*''))**",
+            ]
+        );
+
+        let reparsed = Fastq::parse(&written).unwrap();
+        for (original, reparsed) in fastq.sequences_iter().zip(reparsed.sequences_iter()) {
+            assert_eq!(original.sequence_name(), reparsed.sequence_name());
+            assert_eq!(original.sequence_str(), reparsed.sequence_str());
+            assert_eq!(original.quality_str(), reparsed.quality_str());
+        }
+    }
+
+    #[test]
+    fn test_fastq_write_length_mismatch() {
+        let sequence = FastqSequence::new(
+            FastqMetadata::new("broken".into(), None),
+            "ACGT".into(),
+            "III".into(),
+        );
+        let error = FastqWriter::new()
+            .write_sequence(&mut Vec::new(), &sequence)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            crate::Error::FastqError(error) if matches!(
+                *error,
+                crate::fastq::Error::FastqLengthMismatch { sequence_length: 4, quality_length: 3, .. }
+            )
+        ));
+    }
+}
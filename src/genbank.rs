@@ -2,9 +2,19 @@
 
 mod error;
 mod genbank;
+mod genbank_edit;
+mod genbank_feature_index;
 mod genbank_feature_table;
+mod genbank_location;
+mod genbank_locus;
 mod genbank_metadata_table;
+mod genbank_qualifier_value;
+mod genbank_reader;
 mod genbank_sequence;
+mod genbank_translation;
+mod genbank_validation;
+mod genbank_vocabulary;
+mod genbank_writer;
 mod parser;
 
 pub(crate) use self::error::{Error, Result};
@@ -12,6 +22,19 @@ pub(crate) use self::parser::Rule;
 
 pub use genbank::Genbank;
 
-pub use self::genbank_feature_table::{GenbankFeature, GenbankFeatureTable};
-pub use self::genbank_metadata_table::GenbankMetadataTable;
+pub use self::genbank_feature_index::GenbankFeatureIndex;
+pub use self::genbank_feature_table::{
+    GenbankFeature, GenbankFeatureQualifier, GenbankFeatureTable,
+};
+pub use self::genbank_location::{
+    GenbankLocation, GenbankLocationPart, GenbankPosition, GenbankStrand,
+};
+pub use self::genbank_locus::{GenbankLengthUnit, GenbankLocus, GenbankTopology};
+pub use self::genbank_metadata_table::{GenbankMetadataEntry, GenbankMetadataTable};
+pub use self::genbank_qualifier_value::{GenbankDbXref, GenbankDirection};
+pub use self::genbank_reader::GenbankReader;
 pub use self::genbank_sequence::GenbankSequence;
+pub use self::genbank_translation::GenbankTranslation;
+pub use self::genbank_validation::GenbankValidationIssue;
+pub use self::genbank_vocabulary::{GenbankFeatureKey, GenbankQualifierKey};
+pub use self::genbank_writer::GenbankWriter;
@@ -1,6 +1,7 @@
 type GenbankParseError = pest::error::Error<super::Rule>;
 
 #[derive(Debug, thiserror::Error)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     #[error("Genbank parsing error: {0}")]
     GenbankParseError(#[from] GenbankParseError),
@@ -9,6 +10,21 @@ pub enum Error {
         expected: Option<super::Rule>,
         actual: Option<super::Rule>,
     },
+    #[error("Genbank value error: Invalid {field} {value:?}")]
+    GenbankValueError { field: &'static str, value: String },
+    #[error("Genbank length mismatch: LOCUS of {name:?} declares {locus_length}, ORIGIN contains {sequence_length}")]
+    GenbankLengthMismatch {
+        name: String,
+        locus_length: usize,
+        sequence_length: usize,
+    },
+    #[error("Genbank topology error: Cannot {operation} a linear sequence")]
+    GenbankTopologyError { operation: &'static str },
+    #[error("Genbank location error: Cannot resolve {location:?}: {reason}")]
+    GenbankLocationError {
+        location: String,
+        reason: &'static str,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, Box<Error>>;
@@ -1,19 +1,26 @@
 use pest::{iterators::Pair, Parser};
 
 use crate::{
-    helper::{PairExt, PairOptionExt},
+    helper::{raw_lines, PairExt, PairOptionExt},
     FileFormat,
 };
 
 use super::{
-    genbank_feature_table::GenbankFeatureQualifier,
+    genbank_feature_table::{normalize_qualifier_value, GenbankFeatureQualifier},
+    genbank_metadata_table::GenbankMetadataEntry,
+    genbank_sequence::GenbankRawLayout,
     parser::{GenbankParser, Rule},
-    GenbankFeature, GenbankFeatureTable, GenbankMetadataTable, GenbankSequence,
+    GenbankFeature, GenbankFeatureTable, GenbankMetadataTable, GenbankSequence, GenbankWriter,
 };
 
+/// Metadata keywords that always belong to the preceding top-level entry.
+const METADATA_SUB_KEYWORDS: &[&str] = &[
+    "ORGANISM", "AUTHORS", "CONSRTM", "TITLE", "JOURNAL", "MEDLINE", "PUBMED", "REMARK",
+];
+
 #[derive(Debug)]
 pub struct Genbank {
-    pub sequence: GenbankSequence,
+    sequences: Vec<GenbankSequence>,
 }
 
 impl FileFormat for Genbank {
@@ -21,8 +28,38 @@ impl FileFormat for Genbank {
     const EXTENSIONS: &'static [&'static str] = &["gb", "gbk"];
 }
 
+impl Genbank {
+    pub fn sequences_iter(&self) -> impl Iterator<Item = &GenbankSequence> {
+        self.sequences.iter()
+    }
+
+    pub fn sequences_iter_mut(&mut self) -> impl Iterator<Item = &mut GenbankSequence> {
+        self.sequences.iter_mut()
+    }
+
+    pub fn sequences_into_iter(self) -> impl Iterator<Item = GenbankSequence> {
+        self.sequences.into_iter()
+    }
+
+    /// Writes all records using the default [`GenbankWriter`] settings.
+    pub fn write<W: std::io::Write>(&self, writer: &mut W) -> crate::Result<()> {
+        GenbankWriter::default().write(writer, self)
+    }
+}
+
 impl Genbank {
     pub fn parse(source: &str) -> crate::Result<Self> {
+        Self::parse_with(source, false)
+    }
+
+    /// Parses the records like [`Genbank::parse`], but additionally keeps the original text of
+    /// every metadata entry, feature and qualifier. Unmodified parts are written back verbatim
+    /// by [`GenbankWriter`], so an unedited file reproduces the input byte for byte.
+    pub fn parse_lossless(source: &str) -> crate::Result<Self> {
+        Self::parse_with(source, true)
+    }
+
+    fn parse_with(source: &str, lossless: bool) -> crate::Result<Self> {
         let root = GenbankParser::parse(Rule::root, source)
             .map_err(|err| Box::new(super::Error::GenbankParseError(err)))?
             .next()
@@ -33,111 +70,201 @@ impl Genbank {
                 })
             })?;
         Ok(Self {
-            sequence: Self::parse_root(root)?,
+            sequences: Self::parse_root(root, lossless)?,
         })
     }
 
-    fn parse_root(root: Pair<Rule>) -> super::Result<GenbankSequence> {
-        let mut root_iter = root.into_inner();
+    fn parse_root(root: Pair<Rule>, lossless: bool) -> super::Result<Vec<GenbankSequence>> {
+        let mut sequences = Vec::new();
+
+        for pair in root.into_inner() {
+            match pair.as_rule() {
+                Rule::record => sequences.push(Self::parse_record(pair, lossless)?),
+                Rule::EOI => break,
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(sequences)
+    }
+
+    fn parse_record(record: Pair<Rule>, lossless: bool) -> super::Result<GenbankSequence> {
+        let input = record.get_input();
+        let record_end = record.as_span().end();
+        let mut record_iter = record.into_inner().peekable();
+        let mut raw_layout = GenbankRawLayout::default();
 
-        let metadata_table = Self::parse_metadata_table(root_iter.next())?;
-        let feature_table = Self::parse_feature_table(root_iter.next())?;
-        let origin_sequence = Self::parse_origin_sequence(root_iter.next())?;
-        root_iter.next().expect_some(Rule::EOI)?;
+        let metadata_table = Self::parse_metadata_table(record_iter.next(), lossless)?;
+
+        let feature_table = record_iter.next_if(|pair| pair.as_rule() == Rule::feature_table);
+        if lossless {
+            // An empty header records that the FEATURES section was missing
+            raw_layout.features_header =
+                Some(feature_table.as_ref().map_or_else(String::new, |pair| {
+                    let start = pair.as_span().start();
+                    raw_lines(input, start, start).to_string()
+                }));
+        }
+        let feature_table = match feature_table {
+            Some(feature_table) => Self::parse_feature_table(feature_table, lossless)?,
+            None => GenbankFeatureTable::default(),
+        };
+
+        let mut unknown_sections = Vec::new();
+        while let Some(pair) = record_iter.next_if(|pair| pair.as_rule() == Rule::unknown_section) {
+            unknown_sections.push(pair.as_str().to_string());
+        }
+
+        let origin_block = record_iter.next();
+        if lossless {
+            if let Some(pair) = &origin_block {
+                let span = pair.as_span();
+                raw_layout.origin = Some(span.as_str().to_string());
+                raw_layout.terminator = Some(input[span.end()..record_end].to_string());
+            }
+        }
+        let origin_sequence = Self::parse_origin_sequence(origin_block)?;
+        record_iter.next().expect_none()?;
 
         Ok(GenbankSequence::new(
             metadata_table,
             feature_table,
+            unknown_sections,
             origin_sequence,
+            raw_layout,
         ))
     }
 
     fn parse_metadata_table(
         metadata_table: Option<Pair<Rule>>,
+        lossless: bool,
     ) -> super::Result<GenbankMetadataTable> {
         let mut metadata_table_map = GenbankMetadataTable::default();
         let metadata_table = metadata_table.expect_some(Rule::metadata_table)?;
+        let input = metadata_table.get_input();
 
         for pair in metadata_table.into_inner() {
             let metadata_entry = pair.expect(Rule::metadata_entry)?;
             let mut metadata_entry_iter = metadata_entry.into_inner();
 
-            let key = metadata_entry_iter
-                .next()
-                .expect_some(Rule::metadata_key)?
-                .as_str();
+            let key = metadata_entry_iter.next().expect_some(Rule::metadata_key)?;
             let value = metadata_entry_iter
                 .next()
-                .expect_some(Rule::metadata_value)?
-                .as_str();
+                .expect_some(Rule::metadata_value)?;
+            let raw = lossless.then(|| {
+                raw_lines(input, key.as_span().start(), value.as_span().end()).to_string()
+            });
 
             metadata_entry_iter.next().expect_none()?;
-            metadata_table_map.insert(key.into(), value.into());
+            let entry = GenbankMetadataEntry::new(key.as_str().into(), value.as_str().into(), raw);
+
+            // Sub-keywords such as ORGANISM or AUTHORS are indented below their parent, but
+            // some writers drop the indentation of the well-known ones
+            let line_start = input[..key.as_span().start()]
+                .rfind('\n')
+                .map_or(0, |index| index + 1);
+            if key.as_span().start() > line_start || METADATA_SUB_KEYWORDS.contains(&key.as_str()) {
+                metadata_table_map.insert_child(entry);
+            } else {
+                metadata_table_map.insert(entry);
+            }
         }
 
         Ok(metadata_table_map)
     }
 
     fn parse_feature_table(
-        feature_table: Option<Pair<Rule>>,
+        feature_table: Pair<Rule>,
+        lossless: bool,
     ) -> super::Result<GenbankFeatureTable> {
-        let feature_table = feature_table.expect_some(Rule::feature_table)?;
         let mut features = Vec::new();
 
         for pair in feature_table.into_inner() {
             let feature_entry = pair.expect(Rule::feature_table_entry)?;
-            let feature = Self::parse_feature_table_entry(feature_entry)?;
+            let feature = Self::parse_feature_table_entry(feature_entry, lossless)?;
             features.push(feature);
         }
 
         Ok(GenbankFeatureTable::new(features))
     }
 
-    fn parse_feature_table_entry(feature_table_entry: Pair<Rule>) -> super::Result<GenbankFeature> {
+    fn parse_feature_table_entry(
+        feature_table_entry: Pair<Rule>,
+        lossless: bool,
+    ) -> super::Result<GenbankFeature> {
         let mut feature_table_entry_iter = feature_table_entry.into_inner();
-        let mut key_value_iter = feature_table_entry_iter
+        let key_value = feature_table_entry_iter
             .next()
-            .expect_some(Rule::feature_key_value)?
-            .into_inner();
+            .expect_some(Rule::feature_key_value)?;
+        let raw = lossless.then(|| {
+            let span = key_value.as_span();
+            raw_lines(span.get_input(), span.start(), span.end()).to_string()
+        });
+        let mut key_value_iter = key_value.into_inner();
         let key = key_value_iter
             .next()
             .expect_some(Rule::feature_key)?
             .as_str();
+        // Long locations are wrapped after a comma
         let location = key_value_iter
             .next()
             .expect_some(Rule::feature_value)?
-            .as_str();
-        let qualifiers =
-            Self::parse_feature_table_entry_qualifiers(feature_table_entry_iter.next())?;
-        let feature = GenbankFeature::new(key.into(), location.into(), qualifiers);
+            .as_str()
+            .split_whitespace()
+            .collect::<String>();
+        let qualifiers = match feature_table_entry_iter.next() {
+            Some(qualifier_list) => {
+                Self::parse_feature_table_entry_qualifiers(qualifier_list, lossless)?
+            }
+            None => Vec::new(),
+        };
+        let feature = GenbankFeature::new(key.into(), location, qualifiers, raw);
         feature_table_entry_iter.next().expect_none()?;
 
         Ok(feature)
     }
 
     fn parse_feature_table_entry_qualifiers(
-        qualifier_list: Option<Pair<Rule>>,
+        qualifier_list: Pair<Rule>,
+        lossless: bool,
     ) -> super::Result<Vec<GenbankFeatureQualifier>> {
         let mut qualifiers = Vec::new();
-        let qualifier_list = qualifier_list.expect_some(Rule::qualifier_list)?;
+        let qualifier_list = qualifier_list.expect(Rule::qualifier_list)?;
 
         for pair in qualifier_list.into_inner() {
             let qualifier_entry = pair.expect(Rule::qualifier_entry)?;
+            let raw = lossless.then(|| {
+                let span = qualifier_entry.as_span();
+                raw_lines(span.get_input(), span.start(), span.end()).to_string()
+            });
             let mut qualifier_iter = qualifier_entry.into_inner();
             let qualifier_key = qualifier_iter
                 .next()
                 .expect_some(Rule::qualifier_key)?
                 .as_str()
                 .trim_start_matches('/');
+            // Flag qualifiers such as /pseudo have no value
             let qualifier_value = qualifier_iter
                 .next()
-                .expect_some(Rule::qualifier_value)?
-                .as_str()
-                .trim_matches('"');
+                .map(|pair| pair.expect(Rule::qualifier_value))
+                .transpose()?
+                .map(|pair| pair.as_str());
+            qualifier_iter.next().expect_none()?;
+            let quoted = qualifier_value.is_some_and(|value| value.starts_with('"'));
+            let qualifier_value = qualifier_value.map(|value| {
+                let value = if quoted {
+                    value[1..value.len() - 1].replace("\"\"", "\"")
+                } else {
+                    value.to_string()
+                };
+                normalize_qualifier_value(qualifier_key, &value)
+            });
 
             qualifiers.push(GenbankFeatureQualifier::new(
                 qualifier_key.into(),
-                qualifier_value.into(),
+                qualifier_value,
+                quoted,
+                raw,
             ));
         }
 
@@ -345,21 +472,188 @@ mod tests {
             "##
         };
         let genbank = Genbank::parse(input).unwrap();
-        let metadata = genbank.sequence.metadata();
+        assert_eq!(genbank.sequences_iter().count(), 1);
+        let sequence = genbank.sequences_iter().next().unwrap();
+        let metadata = sequence.metadata();
         assert_eq!(
             metadata.locus(),
             Some("SCU49845     5028 bp    DNA             PLN       21-JUN-1999")
         );
+        let locus = sequence.locus().unwrap();
+        assert_eq!(
+            (locus.name(), locus.length(), locus.division()),
+            ("SCU49845", 5028, Some("PLN"))
+        );
+        assert!(!sequence.is_circular());
+        assert_eq!(
+            metadata
+                .get("SOURCE/ORGANISM")
+                .map(|value| value.lines().next()),
+            Some(Some("Saccharomyces cerevisiae"))
+        );
+        assert!(metadata.get("REFERENCE[2]/TITLE").is_some());
         assert_eq!(metadata.definition(), Some("Saccharomyces cerevisiae TCP1-beta gene, partial cds, and Axl2p\n            (AXL2) and Rev7p (REV7) genes, complete cds."));
-        let features = genbank.sequence.features();
+        let features = sequence.features();
         let cds = features
             .features_iter()
             .find(|feature| feature.key() == "CDS")
             .unwrap();
         assert_eq!(cds.key(), "CDS");
         assert_eq!(cds.location(), "<1..206");
+        assert_eq!(cds.parsed_location().unwrap().len(), 206);
+        assert!(features
+            .features_iter()
+            .all(|feature| feature.parsed_location().is_ok()));
         assert_eq!(cds.get_qualifier("codon_start").unwrap(), "3");
         assert_eq!(cds.get_qualifier("product").unwrap(), "TCP1-beta");
-        assert_eq!(cds.get_qualifier("translation").unwrap(), "SSIYNGISTSGLDLNNGTIADMRQLGIVESYKLKRAVVSSASEA\n                    AEVLLRVDNIIRARPRTANRQHM");
+        assert_eq!(
+            cds.get_qualifier("translation"),
+            Some("SSIYNGISTSGLDLNNGTIADMRQLGIVESYKLKRAVVSSASEAAEVLLRVDNIIRARPRTANRQHM")
+        );
+        let axl2 = features
+            .features_iter()
+            .find(|feature| feature.key() == "CDS" && feature.get_qualifier("gene") == Some("AXL2"))
+            .unwrap();
+        assert_eq!(
+            axl2.get_qualifier("function"),
+            Some("required for axial budding pattern of S. cerevisiae")
+        );
+        assert!(axl2
+            .get_qualifier("translation")
+            .is_some_and(|translation| translation
+                .starts_with("MTQLQISLLLTATISLLHLVVATPYEAYPIGKQYPPVARVNESFTFQIS")));
+
+        let genbank = Genbank::parse_lossless(input).unwrap();
+        assert_eq!(GenbankWriter::new().write_to_string(&genbank), input);
+    }
+
+    #[test]
+    fn test_genbank_parse_multiple_records() {
+        let input = indoc! {r#"
+            LOCUS       pA                12 bp    DNA     circular SYN 01-JAN-2020
+            DEFINITION  First plasmid.
+            FEATURES             Location/Qualifiers
+                 misc_feature    1..6
+                                 /label="first"
+            ORIGIN
+                    1 acgtacgtac gt
+            //
+            LOCUS       pB                 8 bp    DNA     linear   SYN 01-JAN-2020
+            DEFINITION  Second plasmid.
+            FEATURES             Location/Qualifiers
+                 misc_feature    2..4
+                                 /label="second"
+            ORIGIN
+                    1 ttttgggg
+            //
+
+            LOCUS       pC                 4 bp    DNA     linear   SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 source          1..4
+                                 /organism="synthetic DNA construct"
+            ORIGIN
+                    1 aaaa
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let sequences = genbank.sequences_iter().collect::<Vec<_>>();
+        assert_eq!(sequences.len(), 3);
+        assert_eq!(sequences[0].metadata().definition(), Some("First plasmid."));
+        assert_eq!(sequences[0].sequence(), "acgtacgtacgt");
+        assert_eq!(
+            sequences[1].metadata().definition(),
+            Some("Second plasmid.")
+        );
+        assert_eq!(sequences[1].sequence(), "ttttgggg");
+        assert_eq!(
+            sequences[1]
+                .features()
+                .features_iter()
+                .next()
+                .unwrap()
+                .location(),
+            "2..4"
+        );
+        assert_eq!(sequences[2].sequence(), "aaaa");
+
+        let genbank = Genbank::parse_lossless(input).unwrap();
+        assert_eq!(GenbankWriter::new().write_to_string(&genbank), input);
+    }
+
+    #[test]
+    fn test_genbank_parse_location_and_qualifier_variants() {
+        // Synthetic record combining the location and qualifier forms of the INSDC feature
+        // table definition; see test_genbank_parse for a record from NCBI
+        let input = indoc! {r#"
+            LOCUS       TEST0001                  30 bp    DNA     linear   BCT 01-JAN-2020
+            DEFINITION  Escherichia coli feature table excerpt.
+            FEATURES             Location/Qualifiers
+                 source          1..30
+                                 /organism="Escherichia coli"
+                                 /db_xref="taxon:562"
+                 gene            2..28
+                 misc_feature    17
+                                 /note="the ""quoted"" word"
+                 misc_binding    12^13
+                 CDS             join(2..7,
+                                 10..28)
+                                 /pseudo
+                                 /codon_start=1
+            ORIGIN      12 bp upstream of HindIII site.
+                    1 gatgaaatga ttttaaagac gacgtgataa
+            //
+            LOCUS       TEST0002                   0 bp    DNA     linear   UNA 01-JAN-2020
+            DEFINITION  Record without features.
+            ORIGIN
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let sequences = genbank.sequences_iter().collect::<Vec<_>>();
+        assert_eq!(sequences.len(), 2);
+
+        let features = sequences[0].features().features_iter().collect::<Vec<_>>();
+        assert_eq!(
+            features
+                .iter()
+                .map(|feature| (feature.key(), feature.location()))
+                .collect::<Vec<_>>(),
+            [
+                ("source", "1..30"),
+                ("gene", "2..28"),
+                ("misc_feature", "17"),
+                ("misc_binding", "12^13"),
+                ("CDS", "join(2..7,10..28)")
+            ]
+        );
+        assert!(features
+            .iter()
+            .all(|feature| feature.parsed_location().is_ok()));
+        assert_eq!(features[1].qualifiers().count(), 0);
+        assert_eq!(
+            features[2].get_qualifier("note"),
+            Some("the \"quoted\" word")
+        );
+        let pseudo = features[4].qualifiers().next().unwrap();
+        assert!(pseudo.is_flag());
+        assert_eq!(features[4].get_qualifier("pseudo"), Some(""));
+        assert_eq!(sequences[0].sequence(), "gatgaaatgattttaaagacgacgtgataa");
+
+        assert_eq!(sequences[1].features().features_iter().count(), 0);
+        assert_eq!(sequences[1].sequence(), "");
+
+        let written = GenbankWriter::new().write_to_string(&genbank);
+        assert!(written.contains("/note=\"the \"\"quoted\"\" word\"\n"));
+        assert!(written.contains("/pseudo\n"));
+        let reparsed = Genbank::parse(&written).unwrap();
+        assert_eq!(
+            reparsed
+                .sequences_iter()
+                .map(|sequence| sequence.features().features_iter().count())
+                .collect::<Vec<_>>(),
+            [5, 0]
+        );
+
+        let genbank = Genbank::parse_lossless(input).unwrap();
+        assert_eq!(GenbankWriter::new().write_to_string(&genbank), input);
     }
 }
@@ -0,0 +1,767 @@
+use std::ops::Range;
+
+use super::{GenbankLocation, GenbankPosition, GenbankSequence};
+
+impl GenbankSequence {
+    /// Inserts bases at the given 0-based offset of [`Self::sequence`], see [`Self::replace`].
+    pub fn insert(&mut self, offset: usize, bases: &str) -> crate::Result<()> {
+        self.replace(offset..offset, bases)
+    }
+
+    /// Deletes the bases in the given 0-based range of [`Self::sequence`], see [`Self::replace`].
+    pub fn delete(&mut self, range: Range<usize>) -> crate::Result<()> {
+        self.replace(range, "")
+    }
+
+    /// Replaces the bases in the given 0-based range of [`Self::sequence`] and updates the
+    /// feature locations and the LOCUS length accordingly:
+    ///
+    /// - parts after the edited range are shifted,
+    /// - parts containing the edited range grow or shrink by the difference in length,
+    /// - parts overlapping one end of the edited range are truncated and marked partial with
+    ///   `<` or `>`,
+    /// - parts within the edited range are dropped, as are features without any remaining parts.
+    ///   If the first or last parts of a `join(...)` are dropped, the remaining end on that side
+    ///   is marked partial; dropped inner parts cannot be expressed and leave the ends unchanged.
+    ///
+    /// Ranges crossing the origin of a circular sequence are split into a `join(...)` if the
+    /// edit touches them. Features whose location is unchanged keep their original formatting.
+    pub fn replace(&mut self, range: Range<usize>, bases: &str) -> crate::Result<()> {
+        if range.start > range.end || range.end > self.sequence().len() {
+            return Err(value_error("edit range", format!("{range:?}")).into());
+        }
+        if !bases.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(value_error("sequence", bases.to_string()).into());
+        }
+
+        self.replace_unchecked(range, bases)?;
+
+        Ok(())
+    }
+
+    /// Replaces the bases in a validated range, returning which features were kept.
+    fn replace_unchecked(&mut self, range: Range<usize>, bases: &str) -> crate::Result<Vec<bool>> {
+        let length = self.sequence().len();
+        let kept = self.remap_features(|location| location.edited(&range, bases.len(), length))?;
+
+        self.sequence_mut().replace_range(range, bases);
+        self.sequence_changed();
+
+        Ok(kept)
+    }
+
+    /// Rotates a circular sequence so that it starts at the given 0-based offset, i.e. the
+    /// first `offset` bases are moved to the end.
+    ///
+    /// Features are remapped to the new origin. Parts that now cross the origin are split into
+    /// a `join(...)`, while parts that were split at the previous origin are merged back into
+    /// a single range.
+    pub fn rotate(&mut self, offset: usize) -> crate::Result<()> {
+        if !self.is_circular() {
+            return Err(Box::new(super::Error::GenbankTopologyError {
+                operation: "rotate",
+            })
+            .into());
+        }
+
+        let length = self.sequence().len();
+        if length == 0 || offset.is_multiple_of(length) {
+            return Ok(());
+        }
+        let offset = offset % length;
+        self.remap_features(|location| Some(location.rotated(offset, length)))?;
+
+        let sequence = self.sequence_mut();
+        *sequence = [&sequence[offset..], &sequence[..offset]].concat();
+        self.sequence_changed();
+
+        Ok(())
+    }
+
+    /// Copies the bases from the 0-based offset `start` up to, but excluding, `end` into a new,
+    /// linear record.
+    ///
+    /// On circular records, a `start` after `end` selects the region across the origin.
+    /// Features are renumbered relative to the start of the range; features that are cut by
+    /// the range are clipped and marked partial with `<` or `>`, and features outside of it are
+    /// dropped. The metadata is copied with an updated LOCUS length and topology, while unknown
+    /// sections such as `BASE COUNT` are not carried over.
+    pub fn slice(&self, start: usize, end: usize) -> crate::Result<GenbankSequence> {
+        let length = self.sequence().len();
+        if start > length || end > length {
+            return Err(value_error("slice range", format!("{start}..{end}")).into());
+        }
+
+        let count = if start <= end {
+            end - start
+        } else if self.is_circular() {
+            length - start + end
+        } else {
+            return Err(Box::new(super::Error::GenbankTopologyError {
+                operation: "slice across the origin of",
+            })
+            .into());
+        };
+
+        // Work out the clipped sides up front, as the edits below can no longer tell a part
+        // that ends at the edited range from one that is cut by it
+        let mut clipped = self
+            .features()
+            .features_iter()
+            .map(|feature| {
+                let location = feature.parsed_location()?;
+                Ok(clipped_sides(&location, start, count, length))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let mut fragment = self.clone();
+        fragment.unknown_sections_mut().clear();
+        if start <= end {
+            let kept = fragment.replace_unchecked(end..length, "")?;
+            retain_kept(&mut clipped, &kept);
+            let kept = fragment.replace_unchecked(0..start, "")?;
+            retain_kept(&mut clipped, &kept);
+        } else {
+            fragment.rotate(start)?;
+            let kept = fragment.replace_unchecked(count..length, "")?;
+            retain_kept(&mut clipped, &kept);
+        }
+
+        let mut clipped = clipped.into_iter();
+        fragment.remap_features(|location| {
+            let (before, after) = clipped.next().unwrap_or_default();
+            Some(location.with_partial_bounds(before, after))
+        })?;
+        fragment.set_linear();
+
+        Ok(fragment)
+    }
+
+    /// Replaces the location of every feature by the result of `remap`, dropping features
+    /// without a location, and returns which features were kept. Unchanged features are left
+    /// untouched.
+    fn remap_features(
+        &mut self,
+        mut remap: impl FnMut(&GenbankLocation) -> Option<GenbankLocation>,
+    ) -> crate::Result<Vec<bool>> {
+        let locations = self
+            .features()
+            .features_iter()
+            .map(|feature| {
+                let location = feature.parsed_location()?;
+                let remapped = remap(&location);
+                Ok((location, remapped))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        let kept = locations
+            .iter()
+            .map(|(_, remapped)| remapped.is_some())
+            .collect();
+
+        let mut locations = locations.into_iter();
+        self.features_mut().retain_mut(|feature| {
+            let Some((location, remapped)) = locations.next() else {
+                return true;
+            };
+            match remapped {
+                Some(remapped) if remapped == location => true,
+                Some(remapped) => {
+                    feature.set_location(&remapped.to_string());
+                    true
+                }
+                None => false,
+            }
+        });
+
+        Ok(kept)
+    }
+}
+
+impl GenbankLocation {
+    /// Location after replacing the bases in the given 0-based range of a sequence with the
+    /// given length by `inserted` bases, or `None` if no part of the location remains.
+    pub(crate) fn edited(
+        &self,
+        range: &Range<usize>,
+        inserted: usize,
+        length: usize,
+    ) -> Option<Self> {
+        let removed = range.end - range.start;
+        let shift = |position: usize| position - removed + inserted;
+
+        match self {
+            Self::Single(position) => match edit_span(*position, *position, range, inserted)? {
+                Self::Range(start, _) => Some(Self::Single(start)),
+                location => Some(location),
+            },
+            Self::Range(start, end) if start.value() > end.value() => {
+                if range.start >= end.value() && range.end < start.value() {
+                    return Some(Self::Range(start.with_value(shift(start.value())), *end));
+                }
+                let parts = vec![
+                    Self::Range(*start, GenbankPosition::Exact(length)),
+                    Self::Range(GenbankPosition::Exact(1), *end),
+                ];
+                Self::Join(parts).edited(range, inserted, length)
+            }
+            Self::Range(start, end) => edit_span(*start, *end, range, inserted),
+            Self::Between(start, end) => {
+                if *start <= range.start {
+                    Some(self.clone())
+                } else if *start >= range.end {
+                    let end = if end > start { shift(*end) } else { *end };
+                    Some(Self::Between(shift(*start), end))
+                } else {
+                    None
+                }
+            }
+            Self::Complement(location) => location
+                .edited(range, inserted, length)
+                .map(|location| Self::Complement(Box::new(location))),
+            Self::Join(locations) => {
+                let mut parts = Vec::new();
+                let (mut dropped_first, mut dropped_last) = (false, false);
+                for location in locations {
+                    match location.edited(range, inserted, length) {
+                        Some(Self::Join(inner)) => parts.extend(inner),
+                        Some(location) => parts.push(location),
+                        None => {
+                            dropped_first |= parts.is_empty();
+                            dropped_last = true;
+                            continue;
+                        }
+                    }
+                    dropped_last = false;
+                }
+
+                if let Some(first) = parts.first_mut().filter(|_| dropped_first) {
+                    *first = first.with_partial_start();
+                }
+                if let Some(last) = parts.last_mut().filter(|_| dropped_last) {
+                    *last = last.with_partial_end();
+                }
+                Self::from_parts(parts, Self::Join)
+            }
+            Self::Order(locations) => {
+                let parts = locations
+                    .iter()
+                    .filter_map(|location| location.edited(range, inserted, length))
+                    .collect();
+                Self::from_parts(parts, Self::Order)
+            }
+            Self::Remote(..) => Some(self.clone()),
+        }
+    }
+
+    /// Location on a circular sequence with the given length after moving the first `offset`
+    /// bases to the end.
+    pub(crate) fn rotated(&self, offset: usize, length: usize) -> Self {
+        let rotate = |position: usize| (position + length - 1 - offset) % length + 1;
+        let rotate_position =
+            |position: GenbankPosition| position.with_value(rotate(position.value()));
+
+        match self {
+            Self::Single(position) => Self::Single(rotate_position(*position)),
+            Self::Range(start, end) => {
+                let covers_all = if start.value() <= end.value() {
+                    end.value() - start.value() + 1 == length
+                } else {
+                    start.value() == end.value() + 1
+                };
+                if covers_all {
+                    return Self::Range(start.with_value(1), end.with_value(length));
+                }
+
+                let (start, end) = (rotate_position(*start), rotate_position(*end));
+                if start.value() <= end.value() {
+                    Self::Range(start, end)
+                } else {
+                    Self::Join(vec![
+                        Self::Range(start, GenbankPosition::Exact(length)),
+                        Self::Range(GenbankPosition::Exact(1), end),
+                    ])
+                }
+            }
+            Self::Between(start, end) => Self::Between(rotate(*start), rotate(*end)),
+            Self::Complement(location) => {
+                Self::Complement(Box::new(location.rotated(offset, length)))
+            }
+            Self::Join(locations) => {
+                let mut parts = Vec::new();
+                for location in merge_origin_parts(locations, length) {
+                    match location.rotated(offset, length) {
+                        Self::Join(inner) => parts.extend(inner),
+                        location => parts.push(location),
+                    }
+                }
+                Self::from_parts(parts, Self::Join).unwrap_or_else(|| self.clone())
+            }
+            Self::Order(locations) => Self::Order(
+                locations
+                    .iter()
+                    .map(|location| location.rotated(offset, length))
+                    .collect(),
+            ),
+            Self::Remote(..) => self.clone(),
+        }
+    }
+
+    /// Location marked partial at the end it is read from, e.g. `<5..10` or
+    /// `complement(5..>10)`.
+    fn with_partial_start(&self) -> Self {
+        match self {
+            Self::Single(position) => Self::Single(GenbankPosition::Before(position.value())),
+            Self::Range(start, end) => Self::Range(GenbankPosition::Before(start.value()), *end),
+            Self::Complement(location) => Self::Complement(Box::new(location.with_partial_end())),
+            Self::Join(locations) | Self::Order(locations) => {
+                let mut locations = locations.clone();
+                if let Some(first) = locations.first_mut() {
+                    *first = first.with_partial_start();
+                }
+                self.with_parts(locations)
+            }
+            Self::Between(..) | Self::Remote(..) => self.clone(),
+        }
+    }
+
+    /// Location marked partial at the end it is read to, e.g. `5..>10` or
+    /// `complement(<5..10)`.
+    fn with_partial_end(&self) -> Self {
+        match self {
+            Self::Single(position) => Self::Single(GenbankPosition::After(position.value())),
+            Self::Range(start, end) => Self::Range(*start, GenbankPosition::After(end.value())),
+            Self::Complement(location) => Self::Complement(Box::new(location.with_partial_start())),
+            Self::Join(locations) | Self::Order(locations) => {
+                let mut locations = locations.clone();
+                if let Some(last) = locations.last_mut() {
+                    *last = last.with_partial_end();
+                }
+                self.with_parts(locations)
+            }
+            Self::Between(..) | Self::Remote(..) => self.clone(),
+        }
+    }
+
+    /// Location with its lowest position marked `<` and/or its highest position marked `>`,
+    /// regardless of the strand.
+    fn with_partial_bounds(&self, before: bool, after: bool) -> Self {
+        let lowest = self.start().filter(|_| before);
+        let highest = self.end().filter(|_| after);
+        self.with_partial_positions(lowest, highest)
+    }
+
+    fn with_partial_positions(&self, lowest: Option<usize>, highest: Option<usize>) -> Self {
+        let mark_before = |position: GenbankPosition| {
+            if Some(position.value()) == lowest {
+                GenbankPosition::Before(position.value())
+            } else {
+                position
+            }
+        };
+        let mark_after = |position: GenbankPosition| {
+            if Some(position.value()) == highest {
+                GenbankPosition::After(position.value())
+            } else {
+                position
+            }
+        };
+
+        match self {
+            Self::Single(position) => Self::Single(mark_after(mark_before(*position))),
+            Self::Range(start, end) => Self::Range(mark_before(*start), mark_after(*end)),
+            Self::Complement(location) => {
+                Self::Complement(Box::new(location.with_partial_positions(lowest, highest)))
+            }
+            Self::Join(locations) | Self::Order(locations) => self.with_parts(
+                locations
+                    .iter()
+                    .map(|location| location.with_partial_positions(lowest, highest))
+                    .collect(),
+            ),
+            Self::Between(..) | Self::Remote(..) => self.clone(),
+        }
+    }
+
+    /// Same `join(...)` or `order(...)` operator with other parts.
+    fn with_parts(&self, locations: Vec<Self>) -> Self {
+        match self {
+            Self::Order(_) => Self::Order(locations),
+            _ => Self::Join(locations),
+        }
+    }
+
+    /// Collapses a `join(...)` or `order(...)` with less than two parts.
+    pub(crate) fn from_parts(
+        mut parts: Vec<Self>,
+        operator: fn(Vec<Self>) -> Self,
+    ) -> Option<Self> {
+        match parts.len() {
+            0 => None,
+            1 => parts.pop(),
+            _ => Some(operator(parts)),
+        }
+    }
+}
+
+fn edit_span(
+    start: GenbankPosition,
+    end: GenbankPosition,
+    range: &Range<usize>,
+    inserted: usize,
+) -> Option<GenbankLocation> {
+    let (first, last) = (start.value().saturating_sub(1), end.value());
+    let removed = range.end - range.start;
+    let shift =
+        |position: GenbankPosition| position.with_value(position.value() - removed + inserted);
+
+    Some(if last <= range.start {
+        GenbankLocation::Range(start, end)
+    } else if first >= range.end {
+        GenbankLocation::Range(shift(start), shift(end))
+    } else if range.start <= first && last <= range.end {
+        return None;
+    } else if range.start < first {
+        GenbankLocation::Range(
+            GenbankPosition::Before(range.start + inserted + 1),
+            shift(end),
+        )
+    } else if last < range.end {
+        GenbankLocation::Range(start, GenbankPosition::After(range.start))
+    } else {
+        GenbankLocation::Range(start, shift(end))
+    })
+}
+
+/// Whether the location extends beyond the start and beyond the end of the `count` bases
+/// from the 0-based `start` of a sequence with the given length, which wrap across the origin
+/// if needed. Only parts overlapping the region are considered.
+fn clipped_sides(
+    location: &GenbankLocation,
+    start: usize,
+    count: usize,
+    length: usize,
+) -> (bool, bool) {
+    let (mut before, mut after) = (false, false);
+    if length == 0 {
+        return (before, after);
+    }
+
+    for part in location.parts() {
+        if part.accession().is_some() || part.is_between() {
+            continue;
+        }
+        let (first, last) = (part.start().value().saturating_sub(1), part.end().value());
+        let span = if first < last {
+            last - first
+        } else {
+            length - first + last
+        };
+
+        // Offset of the part relative to the region, with the bases before the region at the
+        // end of the sequence
+        let offset = (first + length - start) % length;
+        before |= offset + span > length;
+        after |= (offset < count && offset + span > count) || offset + span > length + count;
+    }
+
+    (before, after)
+}
+
+/// Drops the entries of features that were removed by an edit.
+fn retain_kept<T>(values: &mut Vec<T>, kept: &[bool]) {
+    let mut kept = kept.iter();
+    values.retain(|_| kept.next().copied().unwrap_or(true));
+}
+
+/// Merges consecutive parts of a `join(...)` that are split at the origin of a circular
+/// sequence, such as `join(4900..5028,1..100)`, into a single range crossing the origin.
+fn merge_origin_parts(locations: &[GenbankLocation], length: usize) -> Vec<GenbankLocation> {
+    use GenbankLocation::{Complement, Range};
+
+    let is_end = |position: &GenbankPosition| *position == GenbankPosition::Exact(length);
+    let is_start = |position: &GenbankPosition| *position == GenbankPosition::Exact(1);
+
+    let mut merged: Vec<GenbankLocation> = Vec::new();
+    for location in locations {
+        let joined = match (merged.last(), location) {
+            (Some(Range(start, before)), Range(after, end))
+                if is_end(before) && is_start(after) =>
+            {
+                Some(Range(*start, *end))
+            }
+            (Some(Complement(first)), Complement(second)) => match (&**first, &**second) {
+                (Range(after, end), Range(start, before)) if is_start(after) && is_end(before) => {
+                    Some(Complement(Box::new(Range(*start, *end))))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match joined {
+            Some(joined) => *merged.last_mut().unwrap() = joined,
+            None => merged.push(location.clone()),
+        }
+    }
+    merged
+}
+
+fn value_error(field: &'static str, value: String) -> Box<super::Error> {
+    Box::new(super::Error::GenbankValueError { field, value })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{
+        genbank::{GenbankLocation, GenbankWriter},
+        Genbank,
+    };
+
+    #[test]
+    fn test_genbank_edit() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     30 bp    DNA     circular SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 source          1..30
+                                 /organism="synthetic construct"
+                 misc_feature    3..8
+                                 /label="a"
+                 misc_feature    complement(12..14)
+                                 /label="b"
+                 misc_feature    join(16..18,22..25)
+                                 /label="c"
+                 misc_feature    28..3
+                                 /label="d"
+            BASE COUNT        8 a      6 c      8 g      8 t
+            ORIGIN
+                    1 aacgttgcaa tttggccatg gatcatggca
+            //
+        "#};
+        let mut genbank = Genbank::parse_lossless(input).unwrap();
+        let sequence = genbank.sequences_iter_mut().next().unwrap();
+        let locations = |sequence: &crate::genbank::GenbankSequence| {
+            sequence
+                .features()
+                .features_iter()
+                .map(|feature| feature.location().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        sequence.insert(10, "GGG").unwrap();
+        assert_eq!(&sequence.sequence()[8..15], "aaGGGtt");
+        assert_eq!(
+            locations(sequence),
+            [
+                "1..33",
+                "3..8",
+                "complement(15..17)",
+                "join(19..21,25..28)",
+                "31..3"
+            ]
+        );
+        assert_eq!(
+            sequence.metadata().locus(),
+            Some("pTest                     33 bp    DNA     circular SYN 01-JAN-2020")
+        );
+
+        sequence.delete(5..16).unwrap();
+        assert_eq!(
+            locations(sequence),
+            [
+                "1..22",
+                "3..>5",
+                "complement(<6..6)",
+                "join(8..10,14..17)",
+                "20..3"
+            ]
+        );
+
+        sequence.replace(0..4, "ttttttt").unwrap();
+        assert_eq!(
+            locations(sequence),
+            [
+                "1..25",
+                "<8..>8",
+                "complement(<9..9)",
+                "join(11..13,17..20)",
+                "23..>25"
+            ]
+        );
+
+        let mut written = Vec::new();
+        GenbankWriter::new()
+            .write_sequence(&mut written, sequence)
+            .unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert!(written.contains("\nBASE COUNT        4 a      4 c      6 g     11 t\n"));
+
+        let range: GenbankLocation = "1..22".parse().unwrap();
+        assert_eq!(range.edited(&(0..4), 7, 22).unwrap().to_string(), "1..25");
+        assert_eq!(range.edited(&(18..22), 7, 22).unwrap().to_string(), "1..25");
+        assert_eq!(range.edited(&(0..22), 7, 22), None);
+
+        let join: GenbankLocation = "join(3..5,20..25)".parse().unwrap();
+        assert_eq!(join.edited(&(0..6), 0, 30).unwrap().to_string(), "<14..19");
+        assert_eq!(join.edited(&(18..30), 0, 30).unwrap().to_string(), "3..>5");
+        let join: GenbankLocation = "join(complement(20..25),complement(3..5))".parse().unwrap();
+        assert_eq!(
+            join.edited(&(0..6), 0, 30).unwrap().to_string(),
+            "complement(<14..19)"
+        );
+        let join: GenbankLocation = "join(3..5,10..12,20..25)".parse().unwrap();
+        assert_eq!(
+            join.edited(&(8..14), 0, 30).unwrap().to_string(),
+            "join(3..5,14..19)"
+        );
+
+        let between: GenbankLocation = "20^21".parse().unwrap();
+        assert_eq!(
+            between.edited(&(10..10), 3, 30).unwrap().to_string(),
+            "23^24"
+        );
+        assert_eq!(
+            between.edited(&(20..25), 0, 30).unwrap().to_string(),
+            "20^21"
+        );
+        assert_eq!(between.edited(&(15..25), 0, 30), None);
+
+        assert!(sequence.insert(100, "a").is_err());
+        assert!(sequence.insert(0, "a c").is_err());
+    }
+
+    #[test]
+    fn test_genbank_rotate() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     20 bp    DNA     circular SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 source          1..20
+                                 /organism="synthetic construct"
+                 misc_feature    3..8
+                                 /label="a"
+                 misc_feature    complement(12..14)
+                                 /label="b"
+                 misc_feature    join(18..20,1..2)
+                                 /label="c"
+                 misc_feature    join(complement(1..2),complement(18..20))
+                                 /label="d"
+                 misc_feature    15..5
+                                 /label="e"
+            ORIGIN
+                    1 aacgttgcaa tttggccatg
+            //
+        "#};
+        let mut genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter_mut().next().unwrap();
+        let locations = |sequence: &crate::genbank::GenbankSequence| {
+            sequence
+                .features()
+                .features_iter()
+                .map(|feature| feature.location().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        sequence.rotate(5).unwrap();
+        assert_eq!(sequence.sequence(), "tgcaatttggccatgaacgt");
+        assert_eq!(
+            locations(sequence),
+            [
+                "1..20",
+                "join(18..20,1..3)",
+                "complement(7..9)",
+                "13..17",
+                "complement(13..17)",
+                "10..20"
+            ]
+        );
+
+        sequence.rotate(15).unwrap();
+        assert_eq!(sequence.sequence(), "aacgttgcaatttggccatg");
+        assert_eq!(
+            locations(sequence),
+            [
+                "1..20",
+                "3..8",
+                "complement(12..14)",
+                "join(18..20,1..2)",
+                "complement(join(18..20,1..2))",
+                "join(15..20,1..5)"
+            ]
+        );
+        assert_eq!(
+            sequence
+                .feature_sequence(sequence.features().features_iter().nth(3).unwrap())
+                .unwrap(),
+            "atgaa"
+        );
+
+        let between: GenbankLocation = "5^6".parse().unwrap();
+        assert_eq!(between.rotated(5, 20).to_string(), "20^1");
+
+        let mut linear = Genbank::parse(&input.replace("circular", "linear    ")).unwrap();
+        let linear = linear.sequences_iter_mut().next().unwrap();
+        assert!(linear.rotate(5).is_err());
+    }
+
+    #[test]
+    fn test_genbank_slice() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     20 bp    DNA     circular SYN 01-JAN-2020
+            DEFINITION  Test plasmid.
+            FEATURES             Location/Qualifiers
+                 source          1..20
+                                 /organism="synthetic construct"
+                 misc_feature    3..8
+                                 /label="a"
+                 misc_feature    complement(12..14)
+                                 /label="b"
+                 misc_feature    join(18..20,1..2)
+                                 /label="c"
+                 misc_feature    join(2..3,10..13)
+                                 /label="d"
+            BASE COUNT        6 a      4 c      4 g      6 t
+            ORIGIN
+                    1 aacgttgcaa tttggccatg
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter().next().unwrap();
+        let locations = |sequence: &crate::genbank::GenbankSequence| {
+            sequence
+                .features()
+                .features_iter()
+                .map(|feature| feature.location().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let fragment = sequence.slice(4, 15).unwrap();
+        assert_eq!(fragment.sequence(), "ttgcaatttgg");
+        assert_eq!(
+            locations(&fragment),
+            ["<1..>11", "<1..4", "complement(8..10)", "<6..9"]
+        );
+        assert_eq!(
+            fragment.metadata().locus(),
+            Some("pTest                     11 bp    DNA     linear   SYN 01-JAN-2020")
+        );
+        assert_eq!(fragment.metadata().definition(), Some("Test plasmid."));
+        assert!(!fragment.is_circular());
+        assert_eq!(fragment.unknown_sections().count(), 0);
+
+        let fragment = sequence.slice(16, 3).unwrap();
+        assert_eq!(fragment.sequence(), "catgaac");
+        assert_eq!(locations(&fragment), ["<1..>7", "7..>7", "2..6", "6..>7"]);
+        assert_eq!(
+            fragment
+                .feature_sequence(fragment.features().features_iter().nth(2).unwrap())
+                .unwrap(),
+            "atgaa"
+        );
+
+        let linear = fragment.slice(5, 2);
+        assert!(linear.is_err());
+        assert!(sequence.slice(0, 21).is_err());
+        assert_eq!(sequence.features().features_iter().count(), 5);
+    }
+}
@@ -0,0 +1,320 @@
+use std::{collections::HashMap, ops::Range};
+
+use super::{GenbankFeatureTable, GenbankSequence};
+
+/// Continuous span of bases covered by a feature, as a 0-based half-open range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct GenbankInterval {
+    start: usize,
+    end: usize,
+    feature: usize,
+}
+
+/// Interval index over the feature locations of a record, see
+/// [`GenbankSequence::feature_index`].
+///
+/// Queries take 0-based offsets into [`GenbankSequence::sequence`] and return indices into
+/// [`GenbankFeatureTable::features_iter`] in ascending order. Like [`GenbankSequence::slice`],
+/// a `start` after `end` selects the region across the origin of a circular record.
+///
+/// Parts crossing the origin are indexed as two spans, and between-positions such as `12^13`
+/// cover their two flanking bases. Remote parts and features with an unparsable location are
+/// not indexed.
+#[derive(Debug, Clone, Default)]
+pub struct GenbankFeatureIndex {
+    length: usize,
+    circular: bool,
+    /// Spans sorted by start, forming an implicit binary search tree.
+    intervals: Vec<GenbankInterval>,
+    /// Largest end within the subtree rooted at each interval.
+    subtree_end: Vec<usize>,
+    /// Largest end and its feature among the intervals up to each one.
+    prefix_end: Vec<(usize, usize)>,
+    spans: HashMap<usize, Vec<Range<usize>>>,
+    by_key: HashMap<String, GenbankFeatureIndex>,
+}
+
+impl GenbankFeatureIndex {
+    pub(crate) fn new(features: &GenbankFeatureTable, length: usize, circular: bool) -> Self {
+        let mut intervals = Vec::new();
+        let mut keys = HashMap::<&str, Vec<GenbankInterval>>::new();
+
+        for (feature_index, feature) in features.features_iter().enumerate() {
+            let Ok(location) = feature.parsed_location() else {
+                continue;
+            };
+
+            for part in location.parts() {
+                if part.accession().is_some() {
+                    continue;
+                }
+                // Between-positions cover the bases on either side, just like a range
+                let (first, last) = (part.start().value(), part.end().value());
+                let spans = if first <= last {
+                    [first.saturating_sub(1)..last, 0..0]
+                } else if circular {
+                    [first - 1..length, 0..last]
+                } else {
+                    continue;
+                };
+
+                for span in spans.into_iter().filter(|span| !span.is_empty()) {
+                    let interval = GenbankInterval {
+                        start: span.start,
+                        end: span.end,
+                        feature: feature_index,
+                    };
+                    intervals.push(interval);
+                    keys.entry(feature.key()).or_default().push(interval);
+                }
+            }
+        }
+
+        let mut index = Self::from_intervals(intervals, length, circular);
+        index.by_key = keys
+            .into_iter()
+            .map(|(key, intervals)| {
+                let index = Self::from_intervals(intervals, length, circular);
+                (key.to_string(), index)
+            })
+            .collect();
+        index
+    }
+
+    fn from_intervals(mut intervals: Vec<GenbankInterval>, length: usize, circular: bool) -> Self {
+        intervals.sort_unstable();
+
+        let mut spans = HashMap::<usize, Vec<Range<usize>>>::new();
+        let mut prefix_end = Vec::with_capacity(intervals.len());
+        for interval in &intervals {
+            spans
+                .entry(interval.feature)
+                .or_default()
+                .push(interval.start..interval.end);
+
+            let previous = prefix_end.last().copied();
+            prefix_end.push(match previous {
+                Some((end, feature))
+                    if end > interval.end
+                        || (end == interval.end && feature < interval.feature) =>
+                {
+                    (end, feature)
+                }
+                _ => (interval.end, interval.feature),
+            });
+        }
+
+        let mut index = Self {
+            length,
+            circular,
+            subtree_end: vec![0; intervals.len()],
+            intervals,
+            prefix_end,
+            spans,
+            by_key: HashMap::new(),
+        };
+        index.build_subtree_end(0, index.intervals.len());
+        index
+    }
+
+    fn build_subtree_end(&mut self, low: usize, high: usize) -> usize {
+        if low >= high {
+            return 0;
+        }
+        let middle = (low + high) / 2;
+        let end = self.intervals[middle]
+            .end
+            .max(self.build_subtree_end(low, middle))
+            .max(self.build_subtree_end(middle + 1, high));
+        self.subtree_end[middle] = end;
+        end
+    }
+
+    /// Index restricted to the features with the given key, e.g. `CDS`.
+    pub fn with_key(&self, key: &str) -> Option<&GenbankFeatureIndex> {
+        self.by_key.get(key)
+    }
+
+    /// Features covering the base at the given offset.
+    pub fn at(&self, offset: usize) -> Vec<usize> {
+        self.overlapping(offset, offset + 1)
+    }
+
+    /// Features covering at least one base from `start` up to, but excluding, `end`.
+    pub fn overlapping(&self, start: usize, end: usize) -> Vec<usize> {
+        let mut features = Vec::new();
+        for range in self.query_ranges(start, end) {
+            self.collect_overlapping(0, self.intervals.len(), &range, &mut features);
+        }
+        features.sort_unstable();
+        features.dedup();
+        features
+    }
+
+    /// Features lying completely within the bases from `start` up to, but excluding, `end`.
+    pub fn within(&self, start: usize, end: usize) -> Vec<usize> {
+        let ranges = self.query_ranges(start, end);
+        self.overlapping(start, end)
+            .into_iter()
+            .filter(|feature| {
+                self.spans[feature].iter().all(|span| {
+                    ranges
+                        .iter()
+                        .any(|range| range.start <= span.start && span.end <= range.end)
+                })
+            })
+            .collect()
+    }
+
+    /// Feature closest to the base at the given offset, measured in bases to its nearest
+    /// covered base and across the origin of circular records. Ties go to the feature listed
+    /// first.
+    pub fn nearest(&self, offset: usize) -> Option<usize> {
+        if let Some(feature) = self.at(offset).first() {
+            return Some(*feature);
+        }
+
+        // Nothing covers the offset, so no interval starting before it reaches past it
+        let mut candidates = Vec::new();
+        let after = self
+            .intervals
+            .partition_point(|interval| interval.start <= offset);
+        if let Some(interval) = self.first_starting_at(after) {
+            candidates.push((interval.start - offset, interval.feature));
+        }
+        if let Some((end, feature)) = after.checked_sub(1).map(|index| self.prefix_end[index]) {
+            candidates.push((offset + 1 - end, feature));
+        }
+
+        if self.circular {
+            if let Some(interval) = self.first_starting_at(0) {
+                let distance = self.length.saturating_sub(offset) + interval.start;
+                candidates.push((distance, interval.feature));
+            }
+            if let Some((end, feature)) = self.prefix_end.last() {
+                let distance = offset + 1 + self.length.saturating_sub(*end);
+                candidates.push((distance, *feature));
+            }
+        }
+
+        candidates.into_iter().min().map(|(_, feature)| feature)
+    }
+
+    /// Interval with the lowest feature index among those sharing the start of the interval
+    /// at `index`.
+    fn first_starting_at(&self, index: usize) -> Option<&GenbankInterval> {
+        let start = self.intervals.get(index)?.start;
+        self.intervals[index..]
+            .iter()
+            .take_while(|interval| interval.start == start)
+            .min_by_key(|interval| interval.feature)
+    }
+
+    /// Query split at the origin; the second range is empty unless the query wraps.
+    fn query_ranges(&self, start: usize, end: usize) -> [Range<usize>; 2] {
+        if start <= end {
+            [start..end, 0..0]
+        } else if self.circular {
+            [start..self.length, 0..end]
+        } else {
+            [0..0, 0..0]
+        }
+    }
+
+    fn collect_overlapping(
+        &self,
+        low: usize,
+        high: usize,
+        range: &Range<usize>,
+        features: &mut Vec<usize>,
+    ) {
+        if low >= high || range.is_empty() {
+            return;
+        }
+        let middle = (low + high) / 2;
+        if self.subtree_end[middle] <= range.start {
+            return;
+        }
+
+        self.collect_overlapping(low, middle, range, features);
+        let interval = &self.intervals[middle];
+        if interval.start < range.end {
+            if range.start < interval.end {
+                features.push(interval.feature);
+            }
+            self.collect_overlapping(middle + 1, high, range, features);
+        }
+    }
+}
+
+impl GenbankSequence {
+    /// Interval index over the feature locations, built on first use and rebuilt after the
+    /// record has been modified.
+    pub fn feature_index(&self) -> &GenbankFeatureIndex {
+        self.feature_index_cache().get_or_init(|| {
+            GenbankFeatureIndex::new(self.features(), self.sequence().len(), self.is_circular())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::Genbank;
+
+    #[test]
+    fn test_genbank_feature_index() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     30 bp    DNA     circular SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 source          1..30
+                                 /organism="synthetic DNA construct"
+                 CDS             3..8
+                                 /label="first"
+                 misc_feature    join(5..6,12..14)
+                                 /label="split"
+                 CDS             complement(26..3)
+                                 /label="wrapped"
+                 misc_binding    19^20
+                                 /label="site"
+            ORIGIN
+                    1 gtgaagtggg cgtgatcacc ggatcatggc
+            //
+        "#};
+        let mut genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter_mut().next().unwrap();
+        let index = sequence.feature_index();
+
+        assert_eq!(index.at(0), [0, 3]);
+        assert_eq!(index.at(9), [0]);
+        assert_eq!(index.at(19), [0, 4]);
+        assert_eq!(index.overlapping(6, 12), [0, 1, 2]);
+        assert_eq!(index.overlapping(28, 1), [0, 3]);
+        assert_eq!(index.within(2, 14), [1, 2]);
+        assert_eq!(index.within(20, 5), [3]);
+
+        let cds = index.with_key("CDS").unwrap();
+        assert_eq!(cds.at(2), [1, 3]);
+        assert_eq!(cds.nearest(10), Some(1));
+        assert_eq!(cds.nearest(18), Some(3));
+        assert_eq!(cds.nearest(16), Some(1));
+        assert!(index.with_key("gene").is_none());
+        let labels = index.with_key("misc_feature").unwrap();
+        assert_eq!(labels.nearest(9), Some(2));
+        assert_eq!(labels.nearest(28), Some(2));
+
+        sequence.delete(0..10).unwrap();
+        let index = sequence.feature_index();
+        assert_eq!(index.at(1), [0, 1]);
+        assert_eq!(index.within(15, 20), [2]);
+        assert_eq!(
+            index
+                .at(9)
+                .into_iter()
+                .map(|feature| sequence.features().get(feature).unwrap().key())
+                .collect::<Vec<_>>(),
+            ["source", "misc_binding"]
+        );
+    }
+}
@@ -1,30 +1,85 @@
-use indexmap::IndexMap;
+use super::GenbankLocation;
 
-#[derive(Debug)]
+/// Qualifiers holding a sequence, whose wrapped lines are joined without spaces.
+const SEQUENCE_QUALIFIERS: &[&str] = &["translation", "rpt_unit_seq"];
+
+#[derive(Debug, Clone)]
 pub struct GenbankFeatureQualifier {
     key: String,
-    value: String,
+    value: Option<String>,
+    quoted: bool,
+    raw: Option<String>,
 }
 
 impl GenbankFeatureQualifier {
-    pub(crate) fn new(key: String, value: String) -> Self {
-        Self { key, value }
+    pub(crate) fn new(
+        key: String,
+        value: Option<String>,
+        quoted: bool,
+        raw: Option<String>,
+    ) -> Self {
+        Self {
+            key,
+            value,
+            quoted,
+            raw,
+        }
     }
 
     pub fn key(&self) -> &str {
         &self.key
     }
 
+    /// Value of the qualifier with continuation lines joined and doubled quotes unescaped;
+    /// empty for flag qualifiers.
     pub fn value(&self) -> &str {
-        &self.value
+        self.value.as_deref().unwrap_or_default()
     }
+
+    /// Whether the qualifier has no value, e.g. `/pseudo`.
+    pub fn is_flag(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Whether the value is a sequence such as `/translation`, which is wrapped at any
+    /// character instead of at spaces.
+    pub fn is_sequence(&self) -> bool {
+        SEQUENCE_QUALIFIERS.contains(&self.key.as_str())
+    }
+
+    /// Whether the value is enclosed in double quotes in the flat file.
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Original source lines of the qualifier, kept by [`crate::Genbank::parse_lossless`]
+    /// until the qualifier is modified.
+    pub(crate) fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+}
+
+/// Joins the continuation lines of a qualifier value: without spaces for sequences, with a
+/// single space for free text.
+pub(crate) fn normalize_qualifier_value(key: &str, value: &str) -> String {
+    let separator = if SEQUENCE_QUALIFIERS.contains(&key) {
+        ""
+    } else {
+        " "
+    };
+    value
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(separator)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GenbankFeature {
     key: String,
     location: String,
-    qualifiers: IndexMap<String, GenbankFeatureQualifier>,
+    qualifiers: Vec<GenbankFeatureQualifier>,
+    raw: Option<String>,
 }
 
 impl GenbankFeature {
@@ -32,14 +87,13 @@ impl GenbankFeature {
         key: String,
         location: String,
         qualifiers: Vec<GenbankFeatureQualifier>,
+        raw: Option<String>,
     ) -> Self {
         Self {
             key,
             location,
-            qualifiers: qualifiers
-                .into_iter()
-                .map(|q| (q.key().to_string(), q))
-                .collect(),
+            qualifiers,
+            raw,
         }
     }
 
@@ -51,16 +105,103 @@ impl GenbankFeature {
         &self.location
     }
 
+    /// Parses [`Self::location`] into a [`GenbankLocation`].
+    pub fn parsed_location(&self) -> crate::Result<GenbankLocation> {
+        self.location.parse()
+    }
+
+    /// Qualifiers in their original order, including repeated keys.
     pub fn qualifiers(&self) -> impl Iterator<Item = &GenbankFeatureQualifier> {
-        self.qualifiers.values()
+        self.qualifiers.iter()
     }
 
+    /// Value of the first qualifier with the given key.
     pub fn get_qualifier(&self, key: &str) -> Option<&str> {
-        self.qualifiers.get(key).map(GenbankFeatureQualifier::value)
+        self.qualifiers
+            .iter()
+            .find(|qualifier| qualifier.key == key)
+            .map(GenbankFeatureQualifier::value)
+    }
+
+    /// Values of all qualifiers with the given key, e.g. every `/db_xref` of a feature.
+    pub fn get_qualifiers<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.qualifiers
+            .iter()
+            .filter(move |qualifier| qualifier.key == key)
+            .map(GenbankFeatureQualifier::value)
+    }
+
+    /// Original source lines of the feature key and location, kept by
+    /// [`crate::Genbank::parse_lossless`] until either is modified.
+    pub(crate) fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
     }
 }
 
-#[derive(Debug, Default)]
+impl GenbankFeature {
+    pub fn set_key(&mut self, key: &str) {
+        self.key = key.into();
+        self.raw = None;
+    }
+
+    pub fn set_location(&mut self, location: &str) {
+        self.location = location.into();
+        self.raw = None;
+    }
+
+    /// Replaces the value of the first qualifier with the given key, or appends a new qualifier.
+    /// Further occurrences of the key are kept.
+    pub fn set_qualifier(&mut self, key: &str, value: &str) {
+        match self.qualifiers.iter().position(|q| q.key == key) {
+            Some(index) => {
+                let quoted = self.qualifiers[index].quoted;
+                self.qualifiers[index] =
+                    GenbankFeatureQualifier::new(key.into(), Some(value.into()), quoted, None);
+            }
+            None => self.add_qualifier(key, value),
+        }
+    }
+
+    /// Appends a qualifier, keeping existing ones with the same key.
+    pub fn add_qualifier(&mut self, key: &str, value: &str) {
+        let quoted = self
+            .qualifiers
+            .iter()
+            .find(|q| q.key == key)
+            .is_none_or(GenbankFeatureQualifier::is_quoted);
+        self.qualifiers.push(GenbankFeatureQualifier::new(
+            key.into(),
+            Some(value.into()),
+            quoted,
+            None,
+        ));
+    }
+
+    /// Removes all qualifiers with the given key.
+    pub fn remove_qualifier(&mut self, key: &str) -> Vec<GenbankFeatureQualifier> {
+        let (removed, kept) = std::mem::take(&mut self.qualifiers)
+            .into_iter()
+            .partition(|q| q.key == key);
+        self.qualifiers = kept;
+        removed
+    }
+
+    /// Removes the first qualifier with the given key and value, keeping other occurrences of
+    /// the key.
+    pub fn remove_qualifier_value(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Option<GenbankFeatureQualifier> {
+        let index = self
+            .qualifiers
+            .iter()
+            .position(|q| q.key == key && q.value() == value)?;
+        Some(self.qualifiers.remove(index))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct GenbankFeatureTable {
     features: Vec<GenbankFeature>,
 }
@@ -73,4 +214,79 @@ impl GenbankFeatureTable {
     pub fn features_iter(&self) -> impl Iterator<Item = &GenbankFeature> {
         self.features.iter()
     }
+
+    pub fn get(&self, index: usize) -> Option<&GenbankFeature> {
+        self.features.get(index)
+    }
+
+    pub fn features_iter_mut(&mut self) -> impl Iterator<Item = &mut GenbankFeature> {
+        self.features.iter_mut()
+    }
+
+    pub(crate) fn retain_mut(&mut self, f: impl FnMut(&mut GenbankFeature) -> bool) {
+        self.features.retain_mut(f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{genbank::GenbankWriter, Genbank};
+
+    #[test]
+    fn test_genbank_repeated_qualifiers() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     30 bp    DNA     linear   SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 CDS             1..15
+                                 /db_xref="GeneID:1"
+                                 /note="first"
+                                 /db_xref="UniProtKB:P1"
+                                 /db_xref="GI:2"
+            ORIGIN
+                    1 gtgaagtggg cgtgatcacc ggatcatggc
+            //
+        "#};
+        let mut genbank = Genbank::parse_lossless(input).unwrap();
+        let sequence = genbank.sequences_iter_mut().next().unwrap();
+        let cds = sequence.features_mut().features_iter_mut().next().unwrap();
+
+        assert_eq!(cds.get_qualifier("db_xref"), Some("GeneID:1"));
+        assert_eq!(
+            cds.get_qualifiers("db_xref").collect::<Vec<_>>(),
+            ["GeneID:1", "UniProtKB:P1", "GI:2"]
+        );
+        assert_eq!(cds.qualifiers().count(), 4);
+
+        cds.set_qualifier("db_xref", "GeneID:3");
+        cds.add_qualifier("note", "second");
+        assert!(cds.remove_qualifier_value("db_xref", "GI:2").is_some());
+        assert!(cds.remove_qualifier_value("db_xref", "GI:2").is_none());
+        assert_eq!(
+            cds.qualifiers()
+                .map(|q| (q.key(), q.value()))
+                .collect::<Vec<_>>(),
+            [
+                ("db_xref", "GeneID:3"),
+                ("note", "first"),
+                ("db_xref", "UniProtKB:P1"),
+                ("note", "second")
+            ]
+        );
+
+        let written = GenbankWriter::new().write_to_string(&genbank);
+        assert_eq!(
+            written,
+            input.replace("GeneID:1", "GeneID:3").replace(
+                "                     /db_xref=\"GI:2\"\n",
+                "                     /note=\"second\"\n"
+            )
+        );
+
+        let sequence = genbank.sequences_iter_mut().next().unwrap();
+        let cds = sequence.features_mut().features_iter_mut().next().unwrap();
+        assert_eq!(cds.remove_qualifier("note").len(), 2);
+        assert_eq!(cds.get_qualifiers("db_xref").count(), 2);
+    }
 }
@@ -0,0 +1,434 @@
+use std::{fmt, str::FromStr};
+
+use pest::{iterators::Pair, Parser};
+
+use crate::helper::{PairExt, PairOptionExt};
+
+use super::parser::{GenbankParser, Rule};
+
+/// A 1-based base position, optionally marked as lying beyond the given number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenbankPosition {
+    /// `n`
+    Exact(usize),
+    /// `<n`: the feature extends beyond the given position towards the start.
+    Before(usize),
+    /// `>n`: the feature extends beyond the given position towards the end.
+    After(usize),
+}
+
+impl GenbankPosition {
+    pub fn value(&self) -> usize {
+        match *self {
+            Self::Exact(value) | Self::Before(value) | Self::After(value) => value,
+        }
+    }
+
+    pub fn is_fuzzy(&self) -> bool {
+        !matches!(self, Self::Exact(_))
+    }
+
+    /// Same kind of position at another base.
+    pub(crate) fn with_value(self, value: usize) -> Self {
+        match self {
+            Self::Exact(_) => Self::Exact(value),
+            Self::Before(_) => Self::Before(value),
+            Self::After(_) => Self::After(value),
+        }
+    }
+}
+
+impl fmt::Display for GenbankPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact(value) => write!(f, "{value}"),
+            Self::Before(value) => write!(f, "<{value}"),
+            Self::After(value) => write!(f, ">{value}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenbankStrand {
+    Forward,
+    Reverse,
+    /// Parts of the location lie on both strands.
+    Mixed,
+}
+
+impl GenbankStrand {
+    fn reverse(self) -> Self {
+        match self {
+            Self::Forward => Self::Reverse,
+            Self::Reverse => Self::Forward,
+            Self::Mixed => Self::Mixed,
+        }
+    }
+}
+
+/// Parsed feature location, as described by the INSDC feature table specification.
+///
+/// Converting back to the flat-file notation is done through [`fmt::Display`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GenbankLocation {
+    /// `n`: a single base.
+    Single(GenbankPosition),
+    /// `a..b`: a continuous range of bases, both ends included.
+    Range(GenbankPosition, GenbankPosition),
+    /// `a^b`: a site between two adjacent bases.
+    Between(usize, usize),
+    /// `complement(location)`: the location on the reverse strand.
+    Complement(Box<GenbankLocation>),
+    /// `join(location,location,...)`: the locations are joined into one contiguous sequence.
+    Join(Vec<GenbankLocation>),
+    /// `order(location,location,...)`: the locations are in the given order, without implying
+    /// that they are joined.
+    Order(Vec<GenbankLocation>),
+    /// `ACC.1:location`: a location in another entry.
+    Remote(String, Box<GenbankLocation>),
+}
+
+/// A single continuous part of a [`GenbankLocation`], as returned by [`GenbankLocation::parts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenbankLocationPart<'a> {
+    accession: Option<&'a str>,
+    start: GenbankPosition,
+    end: GenbankPosition,
+    strand: GenbankStrand,
+    between: bool,
+}
+
+impl GenbankLocationPart<'_> {
+    /// Accession of the entry the part lies in, `None` for the current record.
+    pub fn accession(&self) -> Option<&str> {
+        self.accession
+    }
+
+    pub fn start(&self) -> GenbankPosition {
+        self.start
+    }
+
+    pub fn end(&self) -> GenbankPosition {
+        self.end
+    }
+
+    pub fn strand(&self) -> GenbankStrand {
+        self.strand
+    }
+
+    /// Whether the part is a `a^b` site between two bases rather than a span of bases.
+    pub fn is_between(&self) -> bool {
+        self.between
+    }
+
+    /// Number of bases covered by the part; `0` for between-positions.
+    pub fn len(&self) -> usize {
+        if self.between {
+            return 0;
+        }
+        (self.end.value() + 1).saturating_sub(self.start.value())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl GenbankLocation {
+    /// Continuous parts of the location in the order they are read in, i.e. the parts of a
+    /// `complement(join(...))` are returned last to first.
+    pub fn parts(&self) -> Vec<GenbankLocationPart<'_>> {
+        let mut parts = Vec::new();
+        self.collect_parts(&mut parts, None, false);
+        parts
+    }
+
+    fn collect_parts<'a>(
+        &'a self,
+        parts: &mut Vec<GenbankLocationPart<'a>>,
+        accession: Option<&'a str>,
+        reverse: bool,
+    ) {
+        let strand = if reverse {
+            GenbankStrand::Reverse
+        } else {
+            GenbankStrand::Forward
+        };
+        let part = |start, end, between| GenbankLocationPart {
+            accession,
+            start,
+            end,
+            strand,
+            between,
+        };
+
+        match self {
+            Self::Single(position) => parts.push(part(*position, *position, false)),
+            Self::Range(start, end) => parts.push(part(*start, *end, false)),
+            Self::Between(start, end) => parts.push(part(
+                GenbankPosition::Exact(*start),
+                GenbankPosition::Exact(*end),
+                true,
+            )),
+            Self::Complement(location) => {
+                let offset = parts.len();
+                location.collect_parts(parts, accession, !reverse);
+                parts[offset..].reverse();
+            }
+            Self::Join(locations) | Self::Order(locations) => {
+                for location in locations {
+                    location.collect_parts(parts, accession, reverse);
+                }
+            }
+            Self::Remote(accession, location) => {
+                location.collect_parts(parts, Some(accession), reverse);
+            }
+        }
+    }
+
+    /// Strand of the location; [`GenbankStrand::Mixed`] if its parts lie on both strands.
+    pub fn strand(&self) -> GenbankStrand {
+        match self {
+            Self::Single(_) | Self::Range(..) | Self::Between(..) => GenbankStrand::Forward,
+            Self::Complement(location) => location.strand().reverse(),
+            Self::Join(locations) | Self::Order(locations) => {
+                let mut strands = locations.iter().map(Self::strand);
+                let first = strands.next().unwrap_or(GenbankStrand::Forward);
+                if strands.all(|strand| strand == first) {
+                    first
+                } else {
+                    GenbankStrand::Mixed
+                }
+            }
+            Self::Remote(_, location) => location.strand(),
+        }
+    }
+
+    /// Lowest base position of the parts within the current record.
+    pub fn start(&self) -> Option<usize> {
+        self.local_parts().map(|part| part.start.value()).min()
+    }
+
+    /// Highest base position of the parts within the current record.
+    pub fn end(&self) -> Option<usize> {
+        self.local_parts().map(|part| part.end.value()).max()
+    }
+
+    /// Total number of bases covered by the parts within the current record.
+    pub fn len(&self) -> usize {
+        self.local_parts().map(|part| part.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether any part of the location refers to another entry.
+    pub fn is_remote(&self) -> bool {
+        self.parts().iter().any(|part| part.accession.is_some())
+    }
+
+    fn local_parts(&self) -> impl Iterator<Item = GenbankLocationPart<'_>> {
+        self.parts()
+            .into_iter()
+            .filter(|part| part.accession.is_none())
+    }
+}
+
+impl fmt::Display for GenbankLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list(
+            f: &mut fmt::Formatter<'_>,
+            operator: &str,
+            locations: &[GenbankLocation],
+        ) -> fmt::Result {
+            write!(f, "{operator}(")?;
+            for (index, location) in locations.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(",")?;
+                }
+                write!(f, "{location}")?;
+            }
+            f.write_str(")")
+        }
+
+        match self {
+            Self::Single(position) => write!(f, "{position}"),
+            Self::Range(start, end) => write!(f, "{start}..{end}"),
+            Self::Between(start, end) => write!(f, "{start}^{end}"),
+            Self::Complement(location) => write!(f, "complement({location})"),
+            Self::Join(locations) => list(f, "join", locations),
+            Self::Order(locations) => list(f, "order", locations),
+            Self::Remote(accession, location) => write!(f, "{accession}:{location}"),
+        }
+    }
+}
+
+impl FromStr for GenbankLocation {
+    type Err = crate::Error;
+
+    /// Parses a location in the INSDC notation. Whitespace is ignored, so locations spanning
+    /// several flat-file lines can be passed as they are.
+    fn from_str(source: &str) -> crate::Result<Self> {
+        let source = source
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        let root = GenbankParser::parse(Rule::location_root, &source)
+            .map_err(|err| Box::new(super::Error::GenbankParseError(err)))?
+            .next()
+            .ok_or_else(|| {
+                Box::new(super::Error::GenbankCompileError {
+                    expected: Some(Rule::location_root),
+                    actual: None,
+                })
+            })?;
+        let location = root.into_inner().next().expect_some(Rule::location);
+
+        Ok(Self::parse_location(location.map_err(Box::new)?)?)
+    }
+}
+
+impl GenbankLocation {
+    fn parse_location(location: Pair<Rule>) -> super::Result<Self> {
+        let pair = location
+            .into_inner()
+            .next()
+            .ok_or(super::Error::GenbankCompileError {
+                expected: Some(Rule::location),
+                actual: None,
+            })?;
+
+        Ok(match pair.as_rule() {
+            Rule::location_complement => {
+                let inner = pair.into_inner().next().expect_some(Rule::location)?;
+                Self::Complement(Box::new(Self::parse_location(inner)?))
+            }
+            Rule::location_join => Self::Join(Self::parse_location_list(pair)?),
+            Rule::location_order => Self::Order(Self::parse_location_list(pair)?),
+            Rule::location_remote => {
+                let mut pairs = pair.into_inner();
+                let accession = pairs.next().expect_some(Rule::location_accession)?;
+                let local = pairs.next().ok_or(super::Error::GenbankCompileError {
+                    expected: Some(Rule::location_range),
+                    actual: None,
+                })?;
+                pairs.next().expect_none()?;
+                Self::Remote(
+                    accession.as_str().to_string(),
+                    Box::new(Self::parse_location_local(local)?),
+                )
+            }
+            _ => Self::parse_location_local(pair)?,
+        })
+    }
+
+    fn parse_location_list(pair: Pair<Rule>) -> super::Result<Vec<Self>> {
+        pair.into_inner()
+            .map(|location| Self::parse_location(location.expect(Rule::location)?))
+            .collect()
+    }
+
+    fn parse_location_local(pair: Pair<Rule>) -> super::Result<Self> {
+        Ok(match pair.as_rule() {
+            Rule::location_single => {
+                let position = pair
+                    .into_inner()
+                    .next()
+                    .expect_some(Rule::location_position)?;
+                Self::Single(Self::parse_position(position)?)
+            }
+            Rule::location_range => {
+                let mut pairs = pair.into_inner();
+                let start = pairs.next().expect_some(Rule::location_position)?;
+                let end = pairs.next().expect_some(Rule::location_position)?;
+                pairs.next().expect_none()?;
+                Self::Range(Self::parse_position(start)?, Self::parse_position(end)?)
+            }
+            Rule::location_between => {
+                let mut pairs = pair.into_inner();
+                let start = pairs.next().expect_some(Rule::location_number)?;
+                let end = pairs.next().expect_some(Rule::location_number)?;
+                pairs.next().expect_none()?;
+                Self::Between(Self::parse_number(start)?, Self::parse_number(end)?)
+            }
+            actual => {
+                return Err(Box::new(super::Error::GenbankCompileError {
+                    expected: Some(Rule::location_range),
+                    actual: Some(actual),
+                }))
+            }
+        })
+    }
+
+    fn parse_position(position: Pair<Rule>) -> super::Result<GenbankPosition> {
+        let mut pairs = position.into_inner().peekable();
+        let fuzzy = pairs
+            .next_if(|pair| pair.as_rule() == Rule::location_fuzzy)
+            .map(|pair| pair.as_str());
+        let value = Self::parse_number(pairs.next().expect_some(Rule::location_number)?)?;
+        pairs.next().expect_none()?;
+
+        Ok(match fuzzy {
+            Some("<") => GenbankPosition::Before(value),
+            Some(_) => GenbankPosition::After(value),
+            None => GenbankPosition::Exact(value),
+        })
+    }
+
+    fn parse_number(number: Pair<Rule>) -> super::Result<usize> {
+        number.as_str().parse().map_err(|_| {
+            Box::new(super::Error::GenbankValueError {
+                field: "location",
+                value: number.as_str().to_string(),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genbank_location_parse() {
+        let location: GenbankLocation = "complement(join(<1..206,3300..>4000))".parse().unwrap();
+        assert_eq!(
+            location,
+            GenbankLocation::Complement(Box::new(GenbankLocation::Join(vec![
+                GenbankLocation::Range(GenbankPosition::Before(1), GenbankPosition::Exact(206)),
+                GenbankLocation::Range(GenbankPosition::Exact(3300), GenbankPosition::After(4000)),
+            ])))
+        );
+        assert_eq!(location.strand(), GenbankStrand::Reverse);
+        assert_eq!((location.start(), location.end()), (Some(1), Some(4000)));
+        assert_eq!(location.len(), 206 + 701);
+        assert_eq!(
+            location
+                .parts()
+                .iter()
+                .map(|part| (part.start().value(), part.end().value()))
+                .collect::<Vec<_>>(),
+            [(3300, 4000), (1, 206)]
+        );
+        assert_eq!(
+            location.to_string(),
+            "complement(join(<1..206,3300..>4000))"
+        );
+
+        let location: GenbankLocation = "order(join(1..10,\n  complement(20)),J00194.1:100^101,30)"
+            .parse()
+            .unwrap();
+        assert_eq!(location.strand(), GenbankStrand::Mixed);
+        assert!(location.is_remote());
+        assert_eq!((location.start(), location.end()), (Some(1), Some(30)));
+        assert_eq!(location.len(), 12);
+        assert_eq!(
+            location.to_string(),
+            "order(join(1..10,complement(20)),J00194.1:100^101,30)"
+        );
+
+        assert!("join(1..10".parse::<GenbankLocation>().is_err());
+        assert!("complement(1..)".parse::<GenbankLocation>().is_err());
+    }
+}
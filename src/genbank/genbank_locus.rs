@@ -0,0 +1,273 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GenbankTopology {
+    #[default]
+    Linear,
+    Circular,
+}
+
+impl GenbankTopology {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GenbankTopology::Linear => "linear",
+            GenbankTopology::Circular => "circular",
+        }
+    }
+}
+
+/// Unit of the sequence length in the LOCUS line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GenbankLengthUnit {
+    /// `bp`: base pairs of a nucleotide sequence.
+    #[default]
+    BasePairs,
+    /// `aa`: amino acids of a protein sequence.
+    AminoAcids,
+}
+
+impl GenbankLengthUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GenbankLengthUnit::BasePairs => "bp",
+            GenbankLengthUnit::AminoAcids => "aa",
+        }
+    }
+}
+
+/// Typed contents of the LOCUS line.
+///
+/// Parsing accepts both the NCBI column layout and the looser whitespace-separated lines
+/// written by tools such as SnapGene or ApE. Converting back through [`fmt::Display`] always
+/// produces the NCBI column layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenbankLocus {
+    name: String,
+    length: usize,
+    length_unit: GenbankLengthUnit,
+    molecule_type: Option<String>,
+    topology: GenbankTopology,
+    division: Option<String>,
+    date: Option<String>,
+}
+
+impl GenbankLocus {
+    pub fn new(name: &str, length: usize) -> Self {
+        Self {
+            name: name.into(),
+            length,
+            length_unit: GenbankLengthUnit::default(),
+            molecule_type: None,
+            topology: GenbankTopology::default(),
+            division: None,
+            date: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn length_unit(&self) -> GenbankLengthUnit {
+        self.length_unit
+    }
+
+    /// Molecule type including an optional strandedness prefix, e.g. `DNA`, `mRNA` or `ds-DNA`.
+    pub fn molecule_type(&self) -> Option<&str> {
+        self.molecule_type.as_deref()
+    }
+
+    /// Topology of the molecule; linear unless the LOCUS line says otherwise.
+    pub fn topology(&self) -> GenbankTopology {
+        self.topology
+    }
+
+    pub fn is_circular(&self) -> bool {
+        self.topology == GenbankTopology::Circular
+    }
+
+    /// Three-letter GenBank division code, e.g. `PLN` or `SYN`.
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// Modification date in the `DD-MMM-YYYY` format.
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+}
+
+impl GenbankLocus {
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.into();
+    }
+
+    pub fn set_length(&mut self, length: usize) {
+        self.length = length;
+    }
+
+    pub fn set_length_unit(&mut self, length_unit: GenbankLengthUnit) {
+        self.length_unit = length_unit;
+    }
+
+    pub fn set_molecule_type(&mut self, molecule_type: Option<&str>) {
+        self.molecule_type = molecule_type.map(Into::into);
+    }
+
+    pub fn set_topology(&mut self, topology: GenbankTopology) {
+        self.topology = topology;
+    }
+
+    pub fn set_division(&mut self, division: Option<&str>) {
+        self.division = division.map(Into::into);
+    }
+
+    pub fn set_date(&mut self, date: Option<&str>) {
+        self.date = date.map(Into::into);
+    }
+}
+
+impl FromStr for GenbankLocus {
+    type Err = crate::Error;
+
+    fn from_str(source: &str) -> crate::Result<Self> {
+        let error = || {
+            Box::new(super::Error::GenbankValueError {
+                field: "LOCUS",
+                value: source.to_string(),
+            })
+        };
+
+        let tokens = source.split_whitespace().collect::<Vec<_>>();
+        let unit_index = tokens
+            .iter()
+            .position(|token| token.eq_ignore_ascii_case("bp") || token.eq_ignore_ascii_case("aa"))
+            .filter(|index| *index >= 2)
+            .ok_or_else(error)?;
+
+        let name = tokens[..unit_index - 1].join(" ");
+        let length = tokens[unit_index - 1].parse().map_err(|_| error())?;
+        let length_unit = match tokens[unit_index].to_ascii_lowercase().as_str() {
+            "aa" => GenbankLengthUnit::AminoAcids,
+            _ => GenbankLengthUnit::BasePairs,
+        };
+
+        let mut locus = GenbankLocus {
+            name,
+            length,
+            length_unit,
+            ..GenbankLocus::new("", length)
+        };
+        for (index, token) in tokens[unit_index + 1..].iter().enumerate() {
+            if token.eq_ignore_ascii_case("linear") {
+                locus.topology = GenbankTopology::Linear;
+            } else if token.eq_ignore_ascii_case("circular") {
+                locus.topology = GenbankTopology::Circular;
+            } else if is_date(token) {
+                locus.date = Some(token.to_string());
+            } else if index == 0 {
+                locus.molecule_type = Some(token.to_string());
+            } else if locus.division.is_none() {
+                locus.division = Some(token.to_string());
+            } else {
+                return Err(error().into());
+            }
+        }
+
+        Ok(locus)
+    }
+}
+
+impl fmt::Display for GenbankLocus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        // Name and length share the columns 13 to 40 of the LOCUS line.
+        let length = self.length.to_string();
+        let padding = 28usize
+            .saturating_sub(self.name.len() + length.len())
+            .max(1);
+        let mut line = format!("{}{:padding$}{length}", self.name, "");
+        write!(line, " {}", self.length_unit.as_str())?;
+
+        let molecule_type = self.molecule_type.as_deref().unwrap_or_default();
+        let (strandedness, molecule_type) = match molecule_type.split_at_checked(3) {
+            Some((prefix, rest)) if prefix.ends_with('-') => (prefix, rest),
+            _ => ("", molecule_type),
+        };
+        write!(line, " {strandedness:<3}{molecule_type:<6}")?;
+        write!(line, "  {:<8}", self.topology.as_str())?;
+        write!(line, " {:<3}", self.division.as_deref().unwrap_or_default())?;
+        write!(line, " {}", self.date.as_deref().unwrap_or_default())?;
+
+        f.write_str(line.trim_end())
+    }
+}
+
+/// Whether the token looks like a `DD-MMM-YYYY` date.
+fn is_date(token: &str) -> bool {
+    let parts = token.split('-').collect::<Vec<_>>();
+    matches!(parts.as_slice(), [day, month, year]
+        if day.len() <= 2 && day.chars().all(|c| c.is_ascii_digit())
+            && month.len() == 3 && month.chars().all(|c| c.is_ascii_alphabetic())
+            && year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genbank_locus_parse() {
+        let ncbi: GenbankLocus =
+            "NC_001422               5386 bp ss-DNA     circular PHG 06-JUL-2018"
+                .parse()
+                .unwrap();
+        assert_eq!(ncbi.name(), "NC_001422");
+        assert_eq!(ncbi.length(), 5386);
+        assert_eq!(ncbi.length_unit(), GenbankLengthUnit::BasePairs);
+        assert_eq!(ncbi.molecule_type(), Some("ss-DNA"));
+        assert!(ncbi.is_circular());
+        assert_eq!(ncbi.division(), Some("PHG"));
+        assert_eq!(ncbi.date(), Some("06-JUL-2018"));
+        assert_eq!(
+            ncbi.to_string(),
+            "NC_001422               5386 bp ss-DNA     circular PHG 06-JUL-2018"
+        );
+
+        let old: GenbankLocus = "SCU49845     5028 bp    DNA             PLN       21-JUN-1999"
+            .parse()
+            .unwrap();
+        assert_eq!(old.topology(), GenbankTopology::Linear);
+        assert_eq!(old.molecule_type(), Some("DNA"));
+        assert_eq!(old.division(), Some("PLN"));
+        assert_eq!(
+            old.to_string(),
+            "SCU49845                5028 bp    DNA     linear   PLN 21-JUN-1999"
+        );
+
+        let snapgene: GenbankLocus = "pUC19        2686 bp DNA     circular SYN 10-JUN-2020"
+            .parse()
+            .unwrap();
+        assert_eq!((snapgene.name(), snapgene.length()), ("pUC19", 2686));
+        assert!(snapgene.is_circular());
+
+        let ape: GenbankLocus = "New_DNA        12 bp ds-DNA   linear       12-JUN-2012"
+            .parse()
+            .unwrap();
+        assert_eq!(ape.molecule_type(), Some("ds-DNA"));
+        assert_eq!(ape.division(), None);
+        assert_eq!(ape.date(), Some("12-JUN-2012"));
+
+        let protein: GenbankLocus = "AAA98665    187 aa".parse().unwrap();
+        assert_eq!(protein.length_unit(), GenbankLengthUnit::AminoAcids);
+        assert_eq!(protein.molecule_type(), None);
+
+        assert!("pUC19 circular".parse::<GenbankLocus>().is_err());
+        assert!("pUC19 many bp DNA".parse::<GenbankLocus>().is_err());
+    }
+}
@@ -1,24 +1,311 @@
-use indexmap::IndexMap;
+use super::GenbankLocus;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
+pub struct GenbankMetadataEntry {
+    key: String,
+    value: String,
+    children: Vec<GenbankMetadataEntry>,
+    raw: Option<String>,
+}
+
+impl GenbankMetadataEntry {
+    pub(crate) fn new(key: String, value: String, raw: Option<String>) -> Self {
+        Self {
+            key,
+            value,
+            children: Vec::new(),
+            raw,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Sub-keywords of the entry, such as the `AUTHORS` and `TITLE` of a `REFERENCE`.
+    pub fn children_iter(&self) -> impl Iterator<Item = &GenbankMetadataEntry> {
+        self.children.iter()
+    }
+
+    pub fn children_iter_mut(&mut self) -> impl Iterator<Item = &mut GenbankMetadataEntry> {
+        self.children.iter_mut()
+    }
+
+    /// Original source lines of the entry without its sub-keywords, kept by
+    /// [`crate::Genbank::parse_lossless`] until the entry is modified.
+    pub(crate) fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+}
+
+impl GenbankMetadataEntry {
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.into();
+        self.raw = None;
+    }
+
+    /// Appends a sub-keyword, keeping existing ones with the same key.
+    pub fn push_child(&mut self, key: &str, value: &str) -> &mut GenbankMetadataEntry {
+        self.children
+            .push(GenbankMetadataEntry::new(key.into(), value.into(), None));
+        self.children.last_mut().unwrap()
+    }
+}
+
+/// Ordered tree of the metadata entries preceding the feature table.
+///
+/// Entries are addressed by paths of `/`-separated keywords, each optionally followed by the
+/// 1-based occurrence among its siblings, e.g. `REFERENCE[2]/AUTHORS`. A keyword without an
+/// occurrence refers to its first occurrence.
+#[derive(Debug, Clone, Default)]
 pub struct GenbankMetadataTable {
-    map: IndexMap<String, String>,
+    entries: Vec<GenbankMetadataEntry>,
 }
 
 impl GenbankMetadataTable {
-    pub(crate) fn insert(&mut self, key: String, value: String) {
-        self.map.insert(key, value);
+    pub(crate) fn insert(&mut self, entry: GenbankMetadataEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Attaches a sub-keyword to the last top-level entry.
+    pub(crate) fn insert_child(&mut self, entry: GenbankMetadataEntry) {
+        match self.entries.last_mut() {
+            Some(parent) => parent.children.push(entry),
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Value of the entry at the given path.
+    pub fn get(&self, path: &str) -> Option<&str> {
+        self.get_entry(path).map(GenbankMetadataEntry::value)
+    }
+
+    pub fn get_entry(&self, path: &str) -> Option<&GenbankMetadataEntry> {
+        let mut entries = &self.entries;
+        let mut entry = None;
+        for (key, occurrence) in parse_path(path)? {
+            let found = find_entry(entries, key, occurrence)?;
+            entries = &found.children;
+            entry = Some(found);
+        }
+        entry
+    }
+
+    pub fn get_entry_mut(&mut self, path: &str) -> Option<&mut GenbankMetadataEntry> {
+        let mut segments = parse_path(path)?.into_iter();
+        let (key, occurrence) = segments.next()?;
+        let mut entry = find_entry_mut(&mut self.entries, key, occurrence)?;
+        for (key, occurrence) in segments {
+            entry = find_entry_mut(&mut entry.children, key, occurrence)?;
+        }
+        Some(entry)
     }
 
-    pub(crate) fn get(&self, key: &str) -> Option<&str> {
-        self.map.get(key).map(|s| s.as_str())
+    /// Replaces the value of the entry at the given path. If the entry does not exist yet, it is
+    /// appended to its parent, which has to exist.
+    pub fn set(&mut self, path: &str, value: &str) -> crate::Result<()> {
+        if let Some(entry) = self.get_entry_mut(path) {
+            entry.set_value(value);
+            return Ok(());
+        }
+
+        let error = || {
+            Box::new(super::Error::GenbankValueError {
+                field: "metadata path",
+                value: path.to_string(),
+            })
+        };
+        let (parent, key) = match path.rsplit_once('/') {
+            Some((parent, key)) => (Some(parent), key),
+            None => (None, path),
+        };
+        let key = key.split_once('[').map_or(key, |(key, _)| key);
+        match parent {
+            Some(parent) => {
+                let parent = self.get_entry_mut(parent).ok_or_else(error)?;
+                parent.push_child(key, value);
+            }
+            None => {
+                self.push(key, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends a top-level entry, keeping existing ones with the same key.
+    pub fn push(&mut self, key: &str, value: &str) -> &mut GenbankMetadataEntry {
+        self.entries
+            .push(GenbankMetadataEntry::new(key.into(), value.into(), None));
+        self.entries.last_mut().unwrap()
+    }
+
+    /// Removes the entry at the given path, including its sub-keywords.
+    pub fn remove(&mut self, path: &str) -> Option<GenbankMetadataEntry> {
+        let (entries, key) = match path.rsplit_once('/') {
+            Some((parent, key)) => (&mut self.get_entry_mut(parent)?.children, key),
+            None => (&mut self.entries, path),
+        };
+        let (key, occurrence) = parse_path(key)?.pop()?;
+        let index = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.key == key)
+            .nth(occurrence - 1)?
+            .0;
+        Some(entries.remove(index))
+    }
+
+    /// Top-level entries in their original order.
+    pub fn entries_iter(&self) -> impl Iterator<Item = &GenbankMetadataEntry> {
+        self.entries.iter()
+    }
+
+    pub fn entries_iter_mut(&mut self) -> impl Iterator<Item = &mut GenbankMetadataEntry> {
+        self.entries.iter_mut()
     }
 
     pub fn locus(&self) -> Option<&str> {
         self.get("LOCUS")
     }
 
+    /// Parses [`Self::locus`] into a [`GenbankLocus`].
+    pub fn parsed_locus(&self) -> crate::Result<GenbankLocus> {
+        let locus = self.locus().ok_or_else(|| {
+            Box::new(super::Error::GenbankValueError {
+                field: "LOCUS",
+                value: String::new(),
+            })
+        })?;
+        locus.parse()
+    }
+
+    /// Replaces the LOCUS line, using the NCBI column layout.
+    pub fn set_locus(&mut self, locus: &GenbankLocus) {
+        match self.get_entry_mut("LOCUS") {
+            Some(entry) => entry.set_value(&locus.to_string()),
+            None => {
+                let entry = GenbankMetadataEntry::new("LOCUS".into(), locus.to_string(), None);
+                self.entries.insert(0, entry);
+            }
+        }
+    }
+
     pub fn definition(&self) -> Option<&str> {
         self.get("DEFINITION")
     }
 }
+
+/// Splits a path into keys and 1-based occurrences.
+fn parse_path(path: &str) -> Option<Vec<(&str, usize)>> {
+    path.split('/')
+        .map(|segment| match segment.split_once('[') {
+            Some((key, occurrence)) => {
+                let occurrence = occurrence.strip_suffix(']')?.parse().ok()?;
+                (occurrence > 0).then_some((key, occurrence))
+            }
+            None => Some((segment, 1)),
+        })
+        .collect()
+}
+
+fn find_entry<'a>(
+    entries: &'a [GenbankMetadataEntry],
+    key: &str,
+    occurrence: usize,
+) -> Option<&'a GenbankMetadataEntry> {
+    entries
+        .iter()
+        .filter(|entry| entry.key == key)
+        .nth(occurrence - 1)
+}
+
+fn find_entry_mut<'a>(
+    entries: &'a mut [GenbankMetadataEntry],
+    key: &str,
+    occurrence: usize,
+) -> Option<&'a mut GenbankMetadataEntry> {
+    entries
+        .iter_mut()
+        .filter(|entry| entry.key == key)
+        .nth(occurrence - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{genbank::GenbankWriter, Genbank};
+
+    #[test]
+    fn test_genbank_metadata_paths() {
+        let input = indoc! {r#"
+            LOCUS       pUC19                   2686 bp    DNA     circular SYN 01-JAN-2020
+            DEFINITION  Cloning vector pUC19.
+            SOURCE      synthetic DNA construct
+              ORGANISM  synthetic DNA construct
+            REFERENCE   1  (bases 1 to 2686)
+              AUTHORS   Yanisch-Perron,C., Vieira,J. and Messing,J.
+              TITLE     Improved M13 phage cloning vectors and host strains
+            REFERENCE   2  (bases 1 to 2686)
+              AUTHORS   Norrander,J., Kempe,T. and Messing,J.
+            FEATURES             Location/Qualifiers
+                 source          1..2686
+                                 /organism="synthetic DNA construct"
+            ORIGIN
+                    1 tcgcgcgttt
+            //
+        "#};
+        let mut genbank = Genbank::parse_lossless(input).unwrap();
+        let metadata = genbank.sequences_iter_mut().next().unwrap().metadata_mut();
+
+        assert_eq!(
+            metadata
+                .entries_iter()
+                .map(|entry| (entry.key(), entry.children_iter().count()))
+                .collect::<Vec<_>>(),
+            [
+                ("LOCUS", 0),
+                ("DEFINITION", 0),
+                ("SOURCE", 1),
+                ("REFERENCE", 2),
+                ("REFERENCE", 1)
+            ]
+        );
+        assert_eq!(
+            metadata.get("SOURCE/ORGANISM"),
+            Some("synthetic DNA construct")
+        );
+        assert_eq!(metadata.get("REFERENCE"), Some("1  (bases 1 to 2686)"));
+        assert_eq!(
+            metadata.get("REFERENCE[2]/AUTHORS"),
+            Some("Norrander,J., Kempe,T. and Messing,J.")
+        );
+        assert_eq!(metadata.get("REFERENCE[2]/TITLE"), None);
+        assert_eq!(metadata.get("REFERENCE[3]"), None);
+        assert_eq!(metadata.get("REFERENCE[0]"), None);
+
+        metadata.set("REFERENCE[2]/TITLE", "pUC plasmids").unwrap();
+        metadata.set("KEYWORDS", ".").unwrap();
+        assert!(metadata.set("COMMENT/NOTE", "missing parent").is_err());
+        assert!(metadata.remove("REFERENCE[1]/TITLE").is_some());
+
+        let written = GenbankWriter::new().write_to_string(&genbank);
+        assert_eq!(
+            written,
+            input
+                .replace(
+                    "  TITLE     Improved M13 phage cloning vectors and host strains\n",
+                    ""
+                )
+                .replace(
+                    "Kempe,T. and Messing,J.\n",
+                    "Kempe,T. and Messing,J.\n  TITLE     pUC plasmids\nKEYWORDS    .\n"
+                )
+        );
+    }
+}
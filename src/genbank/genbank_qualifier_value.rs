@@ -0,0 +1,239 @@
+use std::{fmt, str::FromStr};
+
+use crate::GeneticCode;
+
+use super::{GenbankFeature, GenbankFeatureKey, GenbankFeatureQualifier, GenbankQualifierKey};
+
+/// Value of the `/direction` qualifier of a `rep_origin` or `oriT` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenbankDirection {
+    Left,
+    Right,
+    Both,
+}
+
+impl GenbankDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GenbankDirection::Left => "LEFT",
+            GenbankDirection::Right => "RIGHT",
+            GenbankDirection::Both => "BOTH",
+        }
+    }
+}
+
+impl FromStr for GenbankDirection {
+    type Err = crate::Error;
+
+    fn from_str(source: &str) -> crate::Result<Self> {
+        match source.to_ascii_uppercase().as_str() {
+            "LEFT" => Ok(GenbankDirection::Left),
+            "RIGHT" => Ok(GenbankDirection::Right),
+            "BOTH" => Ok(GenbankDirection::Both),
+            _ => Err(value_error("/direction", source).into()),
+        }
+    }
+}
+
+impl fmt::Display for GenbankDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Cross-reference of the `/db_xref` qualifier in the `database:identifier` format, e.g.
+/// `taxon:4932`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GenbankDbXref {
+    database: String,
+    identifier: String,
+}
+
+impl GenbankDbXref {
+    pub fn new(database: &str, identifier: &str) -> Self {
+        Self {
+            database: database.into(),
+            identifier: identifier.into(),
+        }
+    }
+
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+}
+
+impl FromStr for GenbankDbXref {
+    type Err = crate::Error;
+
+    fn from_str(source: &str) -> crate::Result<Self> {
+        match source.split_once(':') {
+            Some((database, identifier)) if !database.is_empty() && !identifier.is_empty() => {
+                Ok(GenbankDbXref::new(database, identifier))
+            }
+            _ => Err(value_error("/db_xref", source).into()),
+        }
+    }
+}
+
+impl fmt::Display for GenbankDbXref {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.database, self.identifier)
+    }
+}
+
+impl GenbankFeatureQualifier {
+    /// Typed key of the qualifier, or `None` if it is not part of the INSDC vocabulary.
+    pub fn qualifier_key(&self) -> Option<GenbankQualifierKey> {
+        self.key().parse().ok()
+    }
+}
+
+impl GenbankFeature {
+    /// Typed key of the feature, or `None` if it is not part of the INSDC vocabulary.
+    pub fn feature_key(&self) -> Option<GenbankFeatureKey> {
+        self.key().parse().ok()
+    }
+
+    /// Reading frame of the `/codon_start` qualifier, from 1 to 3.
+    pub fn codon_start(&self) -> crate::Result<Option<u8>> {
+        self.qualifier_with(GenbankQualifierKey::CodonStart, parse_codon_start)
+    }
+
+    /// Codon table of the `/transl_table` qualifier.
+    pub fn transl_table(&self) -> crate::Result<Option<&'static GeneticCode>> {
+        self.qualifier_with(GenbankQualifierKey::TranslTable, parse_transl_table)
+    }
+
+    pub fn direction(&self) -> crate::Result<Option<GenbankDirection>> {
+        self.qualifier_with(GenbankQualifierKey::Direction, |value| value.parse().ok())
+    }
+
+    /// Cross-references of all `/db_xref` qualifiers.
+    pub fn db_xrefs(&self) -> crate::Result<Vec<GenbankDbXref>> {
+        self.get_qualifiers(GenbankQualifierKey::DbXref.as_str())
+            .map(str::parse)
+            .collect()
+    }
+
+    fn qualifier_with<T>(
+        &self,
+        key: GenbankQualifierKey,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> crate::Result<Option<T>> {
+        let Some(value) = self.get_qualifier(key.as_str()) else {
+            return Ok(None);
+        };
+        match parse(value.trim()) {
+            Some(value) => Ok(Some(value)),
+            None => Err(value_error(qualifier_field(key), value).into()),
+        }
+    }
+}
+
+fn parse_codon_start(value: &str) -> Option<u8> {
+    value.parse().ok().filter(|value| (1..=3).contains(value))
+}
+
+fn parse_transl_table(value: &str) -> Option<&'static GeneticCode> {
+    value.parse().ok().and_then(GeneticCode::from_id)
+}
+
+/// Whether the value matches the format of a typed qualifier; other qualifiers accept any
+/// value.
+pub(crate) fn is_valid_qualifier_value(key: GenbankQualifierKey, value: &str) -> bool {
+    let value = value.trim();
+    match key {
+        GenbankQualifierKey::CodonStart => parse_codon_start(value).is_some(),
+        GenbankQualifierKey::TranslTable => parse_transl_table(value).is_some(),
+        GenbankQualifierKey::Direction => value.parse::<GenbankDirection>().is_ok(),
+        GenbankQualifierKey::DbXref => value.parse::<GenbankDbXref>().is_ok(),
+        _ => true,
+    }
+}
+
+/// Field name of the typed qualifiers in value errors.
+fn qualifier_field(key: GenbankQualifierKey) -> &'static str {
+    match key {
+        GenbankQualifierKey::CodonStart => "/codon_start",
+        GenbankQualifierKey::TranslTable => "/transl_table",
+        GenbankQualifierKey::Direction => "/direction",
+        GenbankQualifierKey::DbXref => "/db_xref",
+        _ => "qualifier value",
+    }
+}
+
+fn value_error(field: &'static str, value: &str) -> Box<super::Error> {
+    Box::new(super::Error::GenbankValueError {
+        field,
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::Genbank;
+
+    #[test]
+    fn test_genbank_typed_qualifiers() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     30 bp    DNA     circular SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 rep_origin      1..10
+                                 /direction=right
+                 CDS             11..25
+                                 /codon_start=2
+                                 /transl_table=11
+                                 /db_xref="GeneID:1"
+                                 /db_xref="UniProtKB/Swiss-Prot:P1"
+                 CDS             11..25
+                                 /codon_start=4
+                                 /db_xref="GeneID"
+                 my_feature      26..30
+            ORIGIN
+                    1 gtgaagtggg cgtgatcacc ggatcatggc
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter().next().unwrap();
+        let features = sequence.features().features_iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            features[0].feature_key(),
+            Some(GenbankFeatureKey::RepOrigin)
+        );
+        assert_eq!(
+            features[0].direction().unwrap(),
+            Some(GenbankDirection::Right)
+        );
+        assert_eq!(features[0].codon_start().unwrap(), None);
+
+        assert_eq!(features[1].codon_start().unwrap(), Some(2));
+        assert_eq!(
+            features[1].transl_table().unwrap().map(GeneticCode::id),
+            Some(11)
+        );
+        assert_eq!(
+            features[1].db_xrefs().unwrap(),
+            [
+                GenbankDbXref::new("GeneID", "1"),
+                GenbankDbXref::new("UniProtKB/Swiss-Prot", "P1")
+            ]
+        );
+        assert_eq!(
+            features[1].qualifiers().next().unwrap().qualifier_key(),
+            Some(GenbankQualifierKey::CodonStart)
+        );
+
+        assert!(features[2].codon_start().is_err());
+        assert!(features[2].db_xrefs().is_err());
+        assert_eq!(features[3].feature_key(), None);
+        assert_eq!(features[3].key(), "my_feature");
+    }
+}
@@ -0,0 +1,143 @@
+use std::io::{self, BufRead};
+
+use crate::helper::LineReader;
+
+use super::{Genbank, GenbankSequence, Rule};
+
+/// Reads GenBank records one at a time from a [`BufRead`] source.
+///
+/// Only a single record is held in memory at a time. A record ends at a line holding only
+/// `//` without indentation, so qualifier values may contain `//`. Each record is parsed
+/// with [`Genbank::parse`] (or [`Genbank::parse_lossless`]), so the results match parsing
+/// the whole input at once.
+#[derive(Debug)]
+pub struct GenbankReader<R> {
+    lines: LineReader<R>,
+    lossless: bool,
+    failed: bool,
+}
+
+impl<R: BufRead> GenbankReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: LineReader::new(reader),
+            lossless: false,
+            failed: false,
+        }
+    }
+
+    /// Keeps the original text of each record, see [`Genbank::parse_lossless`].
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.lossless = lossless;
+        self
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<String>> {
+        let mut record = String::new();
+
+        while let Some(line) = self.lines.next_line()? {
+            let is_terminator = line.trim_end() == "//";
+            record.push_str(&line);
+            if is_terminator {
+                self.lines
+                    .append_lines_while(&mut record, |line| line.trim().is_empty())?;
+                break;
+            }
+        }
+
+        Ok((!record.is_empty()).then_some(record))
+    }
+
+    fn parse_record(&self, record: &str) -> crate::Result<GenbankSequence> {
+        let genbank = if self.lossless {
+            Genbank::parse_lossless(record)?
+        } else {
+            Genbank::parse(record)?
+        };
+
+        genbank.sequences_into_iter().next().ok_or_else(|| {
+            Box::new(super::Error::GenbankCompileError {
+                expected: Some(Rule::record),
+                actual: None,
+            })
+            .into()
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for GenbankReader<R> {
+    type Item = crate::Result<GenbankSequence>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.read_record() {
+            Ok(record) => record.map(|record| self.parse_record(&record)),
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::genbank::GenbankWriter;
+
+    #[test]
+    fn test_genbank_reader() {
+        let source = indoc! {r#"
+            LOCUS       pA                12 bp    DNA     circular SYN 01-JAN-2020
+            DEFINITION  First plasmid.
+            FEATURES             Location/Qualifiers
+                 misc_feature    1..6
+                                 /label="first"
+                                 /note="see
+                                 // comment"
+            ORIGIN
+                    1 acgtacgtac gt
+            //
+
+            LOCUS       pB                 8 bp    DNA     linear   SYN 01-JAN-2020
+            DEFINITION  Second plasmid.
+            FEATURES             Location/Qualifiers
+                 misc_feature    2..4
+                                 /label="second"
+            ORIGIN
+                    1 ttttgggg
+            //
+        "#};
+        let expected = Genbank::parse(source).unwrap();
+        let streamed = GenbankReader::new(source.as_bytes())
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(streamed.len(), 2);
+        assert_eq!(
+            streamed[0].features().get(0).unwrap().get_qualifier("note"),
+            Some("see // comment")
+        );
+        for (expected, streamed) in expected.sequences_iter().zip(&streamed) {
+            assert_eq!(expected.metadata().locus(), streamed.metadata().locus());
+            assert_eq!(expected.sequence(), streamed.sequence());
+            assert_eq!(
+                expected.features().features_iter().count(),
+                streamed.features().features_iter().count()
+            );
+        }
+
+        let mut written = Vec::new();
+        for sequence in GenbankReader::new(source.as_bytes()).lossless(true) {
+            GenbankWriter::new()
+                .write_sequence(&mut written, &sequence.unwrap())
+                .unwrap();
+        }
+        assert_eq!(String::from_utf8(written).unwrap(), source);
+    }
+}
@@ -1,22 +1,46 @@
-use super::{GenbankFeatureTable, GenbankMetadataTable};
+use std::{borrow::Cow, ops::Range, sync::OnceLock};
 
-#[derive(Debug)]
+use crate::reverse_complement;
+
+use super::{
+    GenbankFeature, GenbankFeatureIndex, GenbankFeatureTable, GenbankLocation, GenbankLocus,
+    GenbankMetadataTable, GenbankStrand, GenbankTopology,
+};
+
+/// Original source text of the record parts that are not covered by the data model,
+/// kept by [`crate::Genbank::parse_lossless`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GenbankRawLayout {
+    pub(crate) features_header: Option<String>,
+    pub(crate) origin: Option<String>,
+    pub(crate) terminator: Option<String>,
+}
+
+#[derive(Debug, Clone)]
 pub struct GenbankSequence {
     metadata: GenbankMetadataTable,
     features: GenbankFeatureTable,
+    unknown_sections: Vec<String>,
     sequence: String,
+    raw_layout: GenbankRawLayout,
+    feature_index: OnceLock<GenbankFeatureIndex>,
 }
 
 impl GenbankSequence {
     pub(crate) fn new(
         metadata: GenbankMetadataTable,
         features: GenbankFeatureTable,
+        unknown_sections: Vec<String>,
         sequence: String,
+        raw_layout: GenbankRawLayout,
     ) -> Self {
         Self {
             metadata,
             features,
+            unknown_sections,
             sequence,
+            raw_layout,
+            feature_index: OnceLock::new(),
         }
     }
 
@@ -24,11 +48,315 @@ impl GenbankSequence {
         &self.metadata
     }
 
+    pub fn metadata_mut(&mut self) -> &mut GenbankMetadataTable {
+        self.feature_index.take();
+        &mut self.metadata
+    }
+
     pub fn features(&self) -> &GenbankFeatureTable {
         &self.features
     }
 
+    pub fn features_mut(&mut self) -> &mut GenbankFeatureTable {
+        self.feature_index.take();
+        &mut self.features
+    }
+
+    /// Verbatim text of the sections between the feature table and ORIGIN that are not
+    /// modeled by the parser, such as `BASE COUNT` or `CONTIG`.
+    pub fn unknown_sections(&self) -> impl Iterator<Item = &str> {
+        self.unknown_sections.iter().map(String::as_str)
+    }
+
+    pub(crate) fn unknown_sections_mut(&mut self) -> &mut Vec<String> {
+        &mut self.unknown_sections
+    }
+
     pub fn sequence(&self) -> &str {
         &self.sequence
     }
+
+    /// Raw access to the bases; callers are responsible for keeping the features in sync and
+    /// calling [`Self::sequence_changed`] afterwards.
+    pub(crate) fn sequence_mut(&mut self) -> &mut String {
+        self.feature_index.take();
+        &mut self.sequence
+    }
+
+    /// Lazily built [`GenbankFeatureIndex`], reset by every mutable accessor.
+    pub(crate) fn feature_index_cache(&self) -> &OnceLock<GenbankFeatureIndex> {
+        &self.feature_index
+    }
+
+    pub(crate) fn raw_layout(&self) -> &GenbankRawLayout {
+        &self.raw_layout
+    }
+
+    /// Drops the original ORIGIN text, recounts `BASE COUNT` and updates the LOCUS length after
+    /// the sequence has been modified. LOCUS lines that [`GenbankLocus`] rejects have their
+    /// length rewritten in place.
+    pub(crate) fn sequence_changed(&mut self) {
+        self.raw_layout.origin = None;
+
+        for section in self.unknown_sections.iter_mut() {
+            if section.starts_with("BASE COUNT") {
+                *section = base_count(&self.sequence);
+            }
+        }
+
+        if let Ok(mut locus) = self.metadata.parsed_locus() {
+            locus.set_length(self.sequence.len());
+            self.metadata.set_locus(&locus);
+            return;
+        }
+
+        let Some(locus) = self.metadata.locus() else {
+            return;
+        };
+        let Some(span) = locus_length_span(locus) else {
+            return;
+        };
+
+        let length = self.sequence.len().to_string();
+        let width = span.end - span.start;
+        let mut prefix = &locus[..span.start];
+        if length.len() > width {
+            let spaces = prefix.len() - prefix.trim_end().len();
+            let excess = (length.len() - width).min(spaces.saturating_sub(1));
+            prefix = &prefix[..prefix.len() - excess];
+        }
+        let locus = format!("{prefix}{length:>width$}{}", &locus[span.end..]);
+        if let Some(entry) = self.metadata.get_entry_mut("LOCUS") {
+            entry.set_value(&locus);
+        }
+    }
+
+    /// Marks the molecule as linear in the LOCUS line.
+    pub(crate) fn set_linear(&mut self) {
+        if let Ok(mut locus) = self.metadata.parsed_locus() {
+            locus.set_topology(GenbankTopology::Linear);
+            self.metadata.set_locus(&locus);
+            return;
+        }
+
+        let Some(locus) = self.metadata.locus() else {
+            return;
+        };
+        let Some(index) = locus.to_ascii_lowercase().find("circular") else {
+            return;
+        };
+        let locus = format!("{}linear  {}", &locus[..index], &locus[index + 8..]);
+        if let Some(entry) = self.metadata.get_entry_mut("LOCUS") {
+            entry.set_value(&locus);
+        }
+    }
+
+    /// Parses the LOCUS line and checks that its length matches the ORIGIN sequence.
+    pub fn locus(&self) -> crate::Result<GenbankLocus> {
+        let locus = self.metadata.parsed_locus()?;
+        if locus.length() != self.sequence.len() {
+            return Err(Box::new(super::Error::GenbankLengthMismatch {
+                name: locus.name().to_string(),
+                locus_length: locus.length(),
+                sequence_length: self.sequence.len(),
+            })
+            .into());
+        }
+
+        Ok(locus)
+    }
+
+    /// Whether the LOCUS line marks the molecule as circular. LOCUS lines that
+    /// [`GenbankLocus`] rejects are searched for the word `circular` instead.
+    pub fn is_circular(&self) -> bool {
+        match self.metadata.parsed_locus() {
+            Ok(locus) => locus.is_circular(),
+            Err(_) => self.metadata.locus().is_some_and(|locus| {
+                locus
+                    .split_whitespace()
+                    .any(|word| word.eq_ignore_ascii_case("circular"))
+            }),
+        }
+    }
+}
+
+impl GenbankSequence {
+    /// Nucleotide sequence covered by the location of the given feature, see
+    /// [`Self::location_sequence`].
+    pub fn feature_sequence(&self, feature: &GenbankFeature) -> crate::Result<String> {
+        self.location_sequence(&feature.parsed_location()?)
+    }
+
+    /// Nucleotide sequence covered by the location, read in the direction of the feature:
+    /// parts are concatenated in order and parts on the reverse strand are reverse complemented.
+    ///
+    /// On circular records, a range whose start lies after its end continues across the origin.
+    /// Between-positions contribute no bases and remote parts cannot be resolved.
+    pub fn location_sequence(&self, location: &GenbankLocation) -> crate::Result<String> {
+        let error = |reason| {
+            Box::new(super::Error::GenbankLocationError {
+                location: location.to_string(),
+                reason,
+            })
+        };
+
+        let mut result = String::new();
+        for part in location.parts() {
+            if part.accession().is_some() {
+                return Err(error("remote locations refer to another entry").into());
+            }
+            if part.is_between() {
+                continue;
+            }
+
+            let (start, end) = (part.start().value(), part.end().value());
+            let in_range = |position| (1..=self.sequence.len()).contains(&position);
+            if !in_range(start) || !in_range(end) {
+                return Err(error("position is outside of the sequence").into());
+            }
+
+            let bases = if start <= end {
+                Cow::Borrowed(&self.sequence[start - 1..end])
+            } else if self.is_circular() {
+                Cow::Owned([&self.sequence[start - 1..], &self.sequence[..end]].concat())
+            } else {
+                return Err(error("range crosses the origin of a linear sequence").into());
+            };
+
+            match part.strand() {
+                GenbankStrand::Reverse => result.push_str(&reverse_complement(&bases)),
+                _ => result.push_str(&bases),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Byte range of the sequence length in the LOCUS value, i.e. the number preceding the
+/// `bp` or `aa` unit, with or without a space in between.
+/// `BASE COUNT` line in the layout written by NCBI, with `others` only when there are any.
+fn base_count(sequence: &str) -> String {
+    let mut counts = [0; 5];
+    for base in sequence.bytes() {
+        let index = match base.to_ascii_lowercase() {
+            b'a' => 0,
+            b'c' => 1,
+            b'g' => 2,
+            b't' => 3,
+            _ => 4,
+        };
+        counts[index] += 1;
+    }
+
+    let mut line = "BASE COUNT  ".to_string();
+    for (count, base) in counts.iter().zip(["a", "c", "g", "t", "others"]) {
+        if *count > 0 || base != "others" {
+            line.push_str(&format!("{count:>7} {base}"));
+        }
+    }
+    line.push('\n');
+    line
+}
+
+fn locus_length_span(locus: &str) -> Option<Range<usize>> {
+    let units = locus.match_indices("bp").chain(locus.match_indices("aa"));
+    units
+        .filter_map(|(unit, _)| {
+            let after = &locus[unit + 2..];
+            if !after.is_empty() && !after.starts_with(char::is_whitespace) {
+                return None;
+            }
+
+            let end = locus[..unit].trim_end().len();
+            let start = locus[..end]
+                .rfind(|c: char| !c.is_ascii_digit())
+                .map_or(0, |index| index + 1);
+            let word_start = start == 0 || locus[..start].ends_with(char::is_whitespace);
+            (start < end && word_start).then_some(start..end)
+        })
+        .min_by_key(|span| span.start)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::Genbank;
+
+    #[test]
+    fn test_genbank_feature_sequence() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     20 bp    DNA     circular SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 misc_feature    3..6
+                                 /label="forward"
+                 misc_feature    complement(3..6)
+                                 /label="reverse"
+                 misc_feature    join(18..20,1..2)
+                                 /label="origin"
+                 misc_feature    complement(join(1..3,7..8))
+                                 /label="joined"
+            ORIGIN
+                    1 aacgttgcaa tttggccatg
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter().next().unwrap();
+        assert!(sequence.is_circular());
+
+        let extracted = sequence
+            .features()
+            .features_iter()
+            .map(|feature| sequence.feature_sequence(feature).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(extracted, ["cgtt", "aacg", "atgaa", "gcgtt"]);
+
+        let wrapping = "19..2".parse().unwrap();
+        assert_eq!(sequence.location_sequence(&wrapping).unwrap(), "tgaa");
+        let remote = "J00194.1:1..2".parse().unwrap();
+        assert!(sequence.location_sequence(&remote).is_err());
+        let outside = "15..21".parse().unwrap();
+        assert!(sequence.location_sequence(&outside).is_err());
+
+        let mut sequence = sequence.clone();
+        assert_eq!(sequence.locus().unwrap().length(), 20);
+        let mut locus = sequence.metadata().parsed_locus().unwrap();
+        locus.set_length(25);
+        sequence.metadata_mut().set_locus(&locus);
+        assert!(sequence.locus().is_err());
+    }
+
+    #[test]
+    fn test_genbank_loose_locus_fallback() {
+        let input = indoc! {r#"
+            LOCUS       pTest 20bp DNA circular SYN 01-JAN-2020 extra
+            FEATURES             Location/Qualifiers
+                 misc_feature    19..2
+                                 /label="origin"
+            ORIGIN
+                    1 aacgttgcaa tttggccatg
+            //
+        "#};
+        let mut genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter_mut().next().unwrap();
+        assert!(sequence.metadata().parsed_locus().is_err());
+        assert!(sequence.is_circular());
+        assert_eq!(sequence.feature_index().at(0), [0]);
+
+        sequence.rotate(5).unwrap();
+        assert_eq!(sequence.sequence(), "tgcaatttggccatgaacgt");
+        sequence.insert(0, "ggggggggggg").unwrap();
+        assert_eq!(
+            sequence.metadata().locus(),
+            Some("pTest 31bp DNA circular SYN 01-JAN-2020 extra")
+        );
+
+        let fragment = sequence.slice(0, 10).unwrap();
+        assert_eq!(
+            fragment.metadata().locus(),
+            Some("pTest 10bp DNA linear   SYN 01-JAN-2020 extra")
+        );
+        assert!(!fragment.is_circular());
+    }
 }
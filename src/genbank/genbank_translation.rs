@@ -0,0 +1,146 @@
+use crate::GeneticCode;
+
+use super::{GenbankFeature, GenbankSequence, GenbankStrand};
+
+/// Protein sequence of a CDS feature, as returned by [`GenbankSequence::translate_feature`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenbankTranslation {
+    protein: String,
+    annotated: Option<String>,
+}
+
+impl GenbankTranslation {
+    /// Translated amino acids, without the terminal stop codon.
+    pub fn protein(&self) -> &str {
+        &self.protein
+    }
+
+    /// Value of the `/translation` qualifier of the feature, if present.
+    pub fn annotated(&self) -> Option<&str> {
+        self.annotated.as_deref()
+    }
+
+    /// Index of the first amino acid that differs from the `/translation` qualifier, or `None`
+    /// if both agree or the qualifier is missing.
+    pub fn mismatch(&self) -> Option<usize> {
+        let annotated = self.annotated.as_deref()?;
+        if annotated == self.protein {
+            return None;
+        }
+
+        let position = self
+            .protein
+            .chars()
+            .zip(annotated.chars())
+            .position(|(protein, annotated)| protein != annotated);
+        Some(position.unwrap_or(self.protein.len().min(annotated.len())))
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.mismatch().is_none()
+    }
+}
+
+impl GenbankSequence {
+    /// Translates the coding sequence of the feature.
+    ///
+    /// The reading frame and codon table are taken from the `/codon_start` and `/transl_table`
+    /// qualifiers. An alternative start codon is translated as methionine unless the 5' end of
+    /// the location is partial (`<`/`>`); the terminal stop codon is dropped unless the 3' end
+    /// is partial.
+    pub fn translate_feature(&self, feature: &GenbankFeature) -> crate::Result<GenbankTranslation> {
+        let location = feature.parsed_location()?;
+        let parts = location.parts();
+        let five_prime_partial = parts.first().is_some_and(|part| match part.strand() {
+            GenbankStrand::Reverse => part.end().is_fuzzy(),
+            _ => part.start().is_fuzzy(),
+        });
+        let three_prime_partial = parts.last().is_some_and(|part| match part.strand() {
+            GenbankStrand::Reverse => part.start().is_fuzzy(),
+            _ => part.end().is_fuzzy(),
+        });
+
+        let codon_start = feature
+            .codon_start()?
+            .map_or(0, |codon_start| codon_start - 1);
+        let code = feature.transl_table()?.unwrap_or(GeneticCode::standard());
+
+        let nucleotides = self.location_sequence(&location)?;
+        let coding = nucleotides
+            .get(usize::from(codon_start)..)
+            .unwrap_or_default();
+        let mut protein = code.translate(coding);
+
+        if !five_prime_partial
+            && coding
+                .get(..3)
+                .is_some_and(|codon| code.is_start_codon(codon))
+        {
+            protein.replace_range(..1, "M");
+        }
+        if !three_prime_partial && protein.ends_with('*') {
+            protein.pop();
+        }
+
+        let annotated = feature.get_qualifier("translation").map(|value| {
+            value
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != '"')
+                .collect()
+        });
+
+        Ok(GenbankTranslation { protein, annotated })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::Genbank;
+
+    #[test]
+    fn test_genbank_translate_feature() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     30 bp    DNA     linear   SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 CDS             1..15
+                                 /translation="MKWA"
+                 CDS             join(1..6,10..15)
+                                 /transl_table=11
+                                 /translation="MKA"
+                 CDS             complement(16..27)
+                                 /translation="MIP"
+                 CDS             <17..>24
+                                 /codon_start=2
+                                 /translation="TA"
+                 CDS             1..15
+                                 /transl_table=7
+            ORIGIN
+                    1 gtgaagtggg cgtgatcacc ggatcatggc
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let sequence = genbank.sequences_iter().next().unwrap();
+        let features = sequence.features().features_iter().collect::<Vec<_>>();
+
+        let translation = sequence.translate_feature(features[0]).unwrap();
+        assert_eq!(translation.protein(), "VKWA");
+        assert_eq!(translation.mismatch(), Some(0));
+
+        let translation = sequence.translate_feature(features[1]).unwrap();
+        assert_eq!(translation.protein(), "MKA");
+        assert!(translation.is_consistent());
+
+        let translation = sequence.translate_feature(features[2]).unwrap();
+        assert_eq!(translation.protein(), "MIR");
+        assert_eq!(translation.annotated(), Some("MIP"));
+        assert_eq!(translation.mismatch(), Some(2));
+
+        let translation = sequence.translate_feature(features[3]).unwrap();
+        assert_eq!(translation.protein(), "TG");
+        assert_eq!(translation.mismatch(), Some(1));
+
+        assert!(sequence.translate_feature(features[4]).is_err());
+    }
+}
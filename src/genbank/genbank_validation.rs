@@ -0,0 +1,147 @@
+use super::{
+    genbank_qualifier_value::is_valid_qualifier_value, GenbankFeature, GenbankFeatureKey,
+    GenbankFeatureTable, GenbankQualifierKey,
+};
+
+/// Deviation from the INSDC feature table definition, as reported by
+/// [`GenbankFeature::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenbankValidationIssue {
+    /// The feature key is not part of the INSDC vocabulary.
+    UnknownFeatureKey(String),
+    /// The qualifier is not part of the INSDC vocabulary, such as the `/label` written by
+    /// plasmid editors.
+    UnknownQualifier(String),
+    /// The qualifier is not allowed on the feature key.
+    DisallowedQualifier {
+        key: GenbankFeatureKey,
+        qualifier: GenbankQualifierKey,
+    },
+    /// The value of the qualifier is malformed, or is missing or present against the format of
+    /// the qualifier.
+    InvalidValue {
+        qualifier: GenbankQualifierKey,
+        value: String,
+    },
+}
+
+impl GenbankFeature {
+    /// Checks the feature key and qualifiers against the INSDC vocabulary. Qualifiers of unknown
+    /// feature keys are only checked for their own key and value.
+    pub fn validate(&self) -> Vec<GenbankValidationIssue> {
+        let mut issues = Vec::new();
+        let feature_key = self.feature_key();
+        if feature_key.is_none() {
+            issues.push(GenbankValidationIssue::UnknownFeatureKey(self.key().into()));
+        }
+
+        for qualifier in self.qualifiers() {
+            let Some(qualifier_key) = qualifier.qualifier_key() else {
+                issues.push(GenbankValidationIssue::UnknownQualifier(
+                    qualifier.key().into(),
+                ));
+                continue;
+            };
+
+            if let Some(key) = feature_key.filter(|key| !key.allows_qualifier(qualifier_key)) {
+                issues.push(GenbankValidationIssue::DisallowedQualifier {
+                    key,
+                    qualifier: qualifier_key,
+                });
+            }
+            if qualifier.is_flag() != qualifier_key.is_flag()
+                || !is_valid_qualifier_value(qualifier_key, qualifier.value())
+            {
+                issues.push(GenbankValidationIssue::InvalidValue {
+                    qualifier: qualifier_key,
+                    value: qualifier.value().into(),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl GenbankFeatureTable {
+    /// Validates all features, see [`GenbankFeature::validate`]. Issues are paired with the
+    /// index of their feature.
+    pub fn validate(&self) -> Vec<(usize, GenbankValidationIssue)> {
+        self.features_iter()
+            .enumerate()
+            .flat_map(|(index, feature)| {
+                feature
+                    .validate()
+                    .into_iter()
+                    .map(move |issue| (index, issue))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::Genbank;
+
+    #[test]
+    fn test_genbank_validate() {
+        let input = indoc! {r#"
+            LOCUS       pTest                     30 bp    DNA     linear   SYN 01-JAN-2020
+            FEATURES             Location/Qualifiers
+                 source          1..30
+                                 /organism="synthetic DNA construct"
+                                 /mol_type="other DNA"
+                 promoter        1..10
+                                 /gene="lacZ"
+                                 /label="lac promoter"
+                 gene            11..25
+                                 /gene="lacZ"
+                                 /translation="MKA"
+                 CDS             11..25
+                                 /codon_start=4
+                                 /pseudo="yes"
+                 my_feature      26..30
+                                 /note="custom"
+            ORIGIN
+                    1 gtgaagtggg cgtgatcacc ggatcatggc
+            //
+        "#};
+        let genbank = Genbank::parse(input).unwrap();
+        let features = genbank.sequences_iter().next().unwrap().features();
+
+        assert_eq!(
+            features.validate(),
+            [
+                (1, GenbankValidationIssue::UnknownQualifier("label".into())),
+                (
+                    2,
+                    GenbankValidationIssue::DisallowedQualifier {
+                        key: GenbankFeatureKey::Gene,
+                        qualifier: GenbankQualifierKey::Translation
+                    }
+                ),
+                (
+                    3,
+                    GenbankValidationIssue::InvalidValue {
+                        qualifier: GenbankQualifierKey::CodonStart,
+                        value: "4".into()
+                    }
+                ),
+                (
+                    3,
+                    GenbankValidationIssue::InvalidValue {
+                        qualifier: GenbankQualifierKey::Pseudo,
+                        value: "yes".into()
+                    }
+                ),
+                (
+                    4,
+                    GenbankValidationIssue::UnknownFeatureKey("my_feature".into())
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,679 @@
+use std::{fmt, str::FromStr};
+
+/// Declares a vocabulary enum together with its flat file spelling.
+macro_rules! vocabulary {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $field:literal {
+            $($(#[$variant_meta:meta])* $variant:ident = $text:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl $name {
+            /// All entries of the vocabulary.
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// Spelling used in the flat file.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $text,)*
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = crate::Error;
+
+            fn from_str(source: &str) -> crate::Result<Self> {
+                Self::ALL
+                    .iter()
+                    .find(|entry| entry.as_str() == source)
+                    .copied()
+                    .ok_or_else(|| {
+                        Box::new(super::Error::GenbankValueError {
+                            field: $field,
+                            value: source.to_string(),
+                        })
+                        .into()
+                    })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+vocabulary! {
+    /// Feature key of the INSDC feature table definition, see
+    /// <https://www.insdc.org/submitting-standards/feature-table/>.
+    GenbankFeatureKey, "feature key" {
+        AssemblyGap = "assembly_gap",
+        CRegion = "C_region",
+        Cds = "CDS",
+        Centromere = "centromere",
+        DLoop = "D-loop",
+        DSegment = "D_segment",
+        Exon = "exon",
+        Gap = "gap",
+        Gene = "gene",
+        IDna = "iDNA",
+        Intron = "intron",
+        JSegment = "J_segment",
+        MatPeptide = "mat_peptide",
+        MiscBinding = "misc_binding",
+        MiscDifference = "misc_difference",
+        MiscFeature = "misc_feature",
+        MiscRecomb = "misc_recomb",
+        MiscRna = "misc_RNA",
+        MiscStructure = "misc_structure",
+        MobileElement = "mobile_element",
+        ModifiedBase = "modified_base",
+        MRna = "mRNA",
+        NcRna = "ncRNA",
+        NRegion = "N_region",
+        OldSequence = "old_sequence",
+        Operon = "operon",
+        OriT = "oriT",
+        PolyASite = "polyA_site",
+        PrecursorRna = "precursor_RNA",
+        PrimTranscript = "prim_transcript",
+        PrimerBind = "primer_bind",
+        Propeptide = "propeptide",
+        ProteinBind = "protein_bind",
+        Regulatory = "regulatory",
+        RepeatRegion = "repeat_region",
+        RepOrigin = "rep_origin",
+        RRna = "rRNA",
+        SRegion = "S_region",
+        SigPeptide = "sig_peptide",
+        Source = "source",
+        StemLoop = "stem_loop",
+        Sts = "STS",
+        Telomere = "telomere",
+        TmRna = "tmRNA",
+        TransitPeptide = "transit_peptide",
+        TRna = "tRNA",
+        Unsure = "unsure",
+        VRegion = "V_region",
+        VSegment = "V_segment",
+        Variation = "variation",
+        ThreePrimeUtr = "3'UTR",
+        FivePrimeUtr = "5'UTR",
+        /// Retired in favour of `regulatory`, but still written by most plasmid editors.
+        Promoter = "promoter",
+        /// Retired in favour of `regulatory`.
+        Enhancer = "enhancer",
+        /// Retired in favour of `regulatory`.
+        Terminator = "terminator",
+        /// Retired in favour of `regulatory`.
+        Rbs = "RBS",
+        /// Retired in favour of `regulatory`.
+        PolyASignal = "polyA_signal",
+        /// Retired in favour of `regulatory`.
+        MinusTenSignal = "-10_signal",
+        /// Retired in favour of `regulatory`.
+        MinusThirtyFiveSignal = "-35_signal",
+        /// Retired in favour of `regulatory`.
+        MiscSignal = "misc_signal",
+        /// Retired in favour of `repeat_region`.
+        Ltr = "LTR",
+    }
+}
+
+vocabulary! {
+    /// Qualifier key of the INSDC feature table definition, without the leading `/`.
+    GenbankQualifierKey, "qualifier key" {
+        Allele = "allele",
+        Altitude = "altitude",
+        Anticodon = "anticodon",
+        ArtificialLocation = "artificial_location",
+        BioMaterial = "bio_material",
+        BoundMoiety = "bound_moiety",
+        CellLine = "cell_line",
+        CellType = "cell_type",
+        Chromosome = "chromosome",
+        CircularRna = "circular_RNA",
+        Citation = "citation",
+        Clone = "clone",
+        CloneLib = "clone_lib",
+        CodonStart = "codon_start",
+        CollectedBy = "collected_by",
+        CollectionDate = "collection_date",
+        Compare = "compare",
+        Country = "country",
+        Cultivar = "cultivar",
+        CultureCollection = "culture_collection",
+        DbXref = "db_xref",
+        DevStage = "dev_stage",
+        Direction = "direction",
+        EcNumber = "EC_number",
+        Ecotype = "ecotype",
+        EnvironmentalSample = "environmental_sample",
+        EstimatedLength = "estimated_length",
+        Exception = "exception",
+        Experiment = "experiment",
+        Focus = "focus",
+        Frequency = "frequency",
+        Function = "function",
+        GapType = "gap_type",
+        Gene = "gene",
+        GeneSynonym = "gene_synonym",
+        GeoLocName = "geo_loc_name",
+        Germline = "germline",
+        Haplogroup = "haplogroup",
+        Haplotype = "haplotype",
+        Host = "host",
+        IdentifiedBy = "identified_by",
+        Inference = "inference",
+        Isolate = "isolate",
+        IsolationSource = "isolation_source",
+        LabHost = "lab_host",
+        LatLon = "lat_lon",
+        LinkageEvidence = "linkage_evidence",
+        LocusTag = "locus_tag",
+        Macronuclear = "macronuclear",
+        Map = "map",
+        MatingType = "mating_type",
+        MetagenomeSource = "metagenome_source",
+        MobileElementType = "mobile_element_type",
+        ModBase = "mod_base",
+        MolType = "mol_type",
+        NcRnaClass = "ncRNA_class",
+        Note = "note",
+        Number = "number",
+        OldLocusTag = "old_locus_tag",
+        Operon = "operon",
+        Organelle = "organelle",
+        Organism = "organism",
+        PcrConditions = "PCR_conditions",
+        PcrPrimers = "PCR_primers",
+        Phenotype = "phenotype",
+        Plasmid = "plasmid",
+        PopVariant = "pop_variant",
+        Product = "product",
+        ProteinId = "protein_id",
+        Proviral = "proviral",
+        Pseudo = "pseudo",
+        Pseudogene = "pseudogene",
+        Rearranged = "rearranged",
+        RecombinationClass = "recombination_class",
+        RegulatoryClass = "regulatory_class",
+        Replace = "replace",
+        RibosomalSlippage = "ribosomal_slippage",
+        RptFamily = "rpt_family",
+        RptType = "rpt_type",
+        RptUnitRange = "rpt_unit_range",
+        RptUnitSeq = "rpt_unit_seq",
+        Satellite = "satellite",
+        Segment = "segment",
+        Serotype = "serotype",
+        Serovar = "serovar",
+        Sex = "sex",
+        SpecimenVoucher = "specimen_voucher",
+        StandardName = "standard_name",
+        Strain = "strain",
+        SubClone = "sub_clone",
+        SubSpecies = "sub_species",
+        SubStrain = "sub_strain",
+        SubmitterSeqid = "submitter_seqid",
+        TagPeptide = "tag_peptide",
+        TissueLib = "tissue_lib",
+        TissueType = "tissue_type",
+        Transgenic = "transgenic",
+        Translation = "translation",
+        TranslExcept = "transl_except",
+        TranslTable = "transl_table",
+        TransSplicing = "trans_splicing",
+        TypeMaterial = "type_material",
+        Variety = "variety",
+    }
+}
+
+use GenbankQualifierKey as Q;
+
+/// Qualifiers allowed on every feature key describing a part of a gene.
+const COMMON_QUALIFIERS: &[Q] = &[
+    Q::Allele,
+    Q::Citation,
+    Q::DbXref,
+    Q::Experiment,
+    Q::Gene,
+    Q::GeneSynonym,
+    Q::Inference,
+    Q::LocusTag,
+    Q::Map,
+    Q::Note,
+    Q::OldLocusTag,
+];
+
+const SOURCE_QUALIFIERS: &[Q] = &[
+    Q::Altitude,
+    Q::BioMaterial,
+    Q::CellLine,
+    Q::CellType,
+    Q::Chromosome,
+    Q::Citation,
+    Q::Clone,
+    Q::CloneLib,
+    Q::CollectedBy,
+    Q::CollectionDate,
+    Q::Country,
+    Q::Cultivar,
+    Q::CultureCollection,
+    Q::DbXref,
+    Q::DevStage,
+    Q::Ecotype,
+    Q::EnvironmentalSample,
+    Q::Focus,
+    Q::GeoLocName,
+    Q::Germline,
+    Q::Haplogroup,
+    Q::Haplotype,
+    Q::Host,
+    Q::IdentifiedBy,
+    Q::Isolate,
+    Q::IsolationSource,
+    Q::LabHost,
+    Q::LatLon,
+    Q::Macronuclear,
+    Q::Map,
+    Q::MatingType,
+    Q::MetagenomeSource,
+    Q::MolType,
+    Q::Note,
+    Q::Organelle,
+    Q::Organism,
+    Q::PcrPrimers,
+    Q::Plasmid,
+    Q::PopVariant,
+    Q::Proviral,
+    Q::Rearranged,
+    Q::Segment,
+    Q::Serotype,
+    Q::Serovar,
+    Q::Sex,
+    Q::SpecimenVoucher,
+    Q::Strain,
+    Q::SubClone,
+    Q::SubSpecies,
+    Q::SubStrain,
+    Q::SubmitterSeqid,
+    Q::TissueLib,
+    Q::TissueType,
+    Q::Transgenic,
+    Q::TypeMaterial,
+    Q::Variety,
+];
+
+impl GenbankFeatureKey {
+    /// Qualifiers the feature table definition allows on the feature key.
+    pub fn allowed_qualifiers(&self) -> impl Iterator<Item = GenbankQualifierKey> {
+        use GenbankFeatureKey as K;
+
+        let (common, specific): (&[Q], &[Q]) = match self {
+            K::Source => (&[], SOURCE_QUALIFIERS),
+            K::Gap => (
+                &[],
+                &[
+                    Q::EstimatedLength,
+                    Q::Experiment,
+                    Q::Inference,
+                    Q::Map,
+                    Q::Note,
+                ],
+            ),
+            K::AssemblyGap => (&[], &[Q::EstimatedLength, Q::GapType, Q::LinkageEvidence]),
+            K::Cds => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::ArtificialLocation,
+                    Q::CircularRna,
+                    Q::CodonStart,
+                    Q::EcNumber,
+                    Q::Exception,
+                    Q::Function,
+                    Q::Number,
+                    Q::Operon,
+                    Q::Product,
+                    Q::ProteinId,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::RibosomalSlippage,
+                    Q::StandardName,
+                    Q::Translation,
+                    Q::TranslExcept,
+                    Q::TranslTable,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::Gene => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::Operon,
+                    Q::Phenotype,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::MRna => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::ArtificialLocation,
+                    Q::CircularRna,
+                    Q::Function,
+                    Q::Operon,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::TRna => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Anticodon,
+                    Q::Function,
+                    Q::Operon,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::NcRna => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::NcRnaClass,
+                    Q::Operon,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::TmRna => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TagPeptide,
+                ],
+            ),
+            K::RRna | K::MiscRna | K::PrecursorRna => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::Operon,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::Exon => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::EcNumber,
+                    Q::Function,
+                    Q::Number,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::Intron => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::Number,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                    Q::TransSplicing,
+                ],
+            ),
+            K::ThreePrimeUtr | K::FivePrimeUtr => (
+                COMMON_QUALIFIERS,
+                &[Q::Function, Q::StandardName, Q::TransSplicing],
+            ),
+            K::MiscFeature => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::Number,
+                    Q::Phenotype,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                ],
+            ),
+            K::MatPeptide | K::SigPeptide | K::TransitPeptide | K::Propeptide => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::EcNumber,
+                    Q::Function,
+                    Q::Product,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                ],
+            ),
+            K::CRegion
+            | K::DSegment
+            | K::JSegment
+            | K::NRegion
+            | K::SRegion
+            | K::VRegion
+            | K::VSegment => (
+                COMMON_QUALIFIERS,
+                &[Q::Product, Q::Pseudo, Q::Pseudogene, Q::StandardName],
+            ),
+            K::Regulatory => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::BoundMoiety,
+                    Q::Function,
+                    Q::Operon,
+                    Q::Phenotype,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::RegulatoryClass,
+                    Q::StandardName,
+                ],
+            ),
+            K::Promoter
+            | K::Enhancer
+            | K::Terminator
+            | K::Rbs
+            | K::PolyASignal
+            | K::MinusTenSignal
+            | K::MinusThirtyFiveSignal
+            | K::MiscSignal => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::BoundMoiety,
+                    Q::Function,
+                    Q::Operon,
+                    Q::Phenotype,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                ],
+            ),
+            K::Operon => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::Operon,
+                    Q::Phenotype,
+                    Q::Pseudo,
+                    Q::Pseudogene,
+                    Q::StandardName,
+                ],
+            ),
+            K::MiscBinding => (COMMON_QUALIFIERS, &[Q::BoundMoiety, Q::Function]),
+            K::ProteinBind => (
+                COMMON_QUALIFIERS,
+                &[Q::BoundMoiety, Q::Function, Q::Operon, Q::StandardName],
+            ),
+            K::MiscDifference => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Clone,
+                    Q::Compare,
+                    Q::Phenotype,
+                    Q::Replace,
+                    Q::StandardName,
+                ],
+            ),
+            K::Variation => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Compare,
+                    Q::Frequency,
+                    Q::Phenotype,
+                    Q::Product,
+                    Q::Replace,
+                    Q::StandardName,
+                ],
+            ),
+            K::OldSequence | K::Unsure => (COMMON_QUALIFIERS, &[Q::Compare, Q::Replace]),
+            K::MiscRecomb => (COMMON_QUALIFIERS, &[Q::RecombinationClass, Q::StandardName]),
+            K::MobileElement => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::MobileElementType,
+                    Q::RptFamily,
+                    Q::RptType,
+                    Q::StandardName,
+                ],
+            ),
+            K::ModifiedBase => (COMMON_QUALIFIERS, &[Q::Frequency, Q::ModBase]),
+            K::OriT => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::BoundMoiety,
+                    Q::Direction,
+                    Q::RptFamily,
+                    Q::RptType,
+                    Q::RptUnitRange,
+                    Q::RptUnitSeq,
+                    Q::StandardName,
+                ],
+            ),
+            K::RepOrigin => (COMMON_QUALIFIERS, &[Q::Direction, Q::StandardName]),
+            K::RepeatRegion | K::Ltr => (
+                COMMON_QUALIFIERS,
+                &[
+                    Q::Function,
+                    Q::RptFamily,
+                    Q::RptType,
+                    Q::RptUnitRange,
+                    Q::RptUnitSeq,
+                    Q::Satellite,
+                    Q::StandardName,
+                ],
+            ),
+            K::Telomere | K::Centromere => (
+                COMMON_QUALIFIERS,
+                &[Q::RptType, Q::RptUnitRange, Q::RptUnitSeq, Q::StandardName],
+            ),
+            K::PrimerBind => (COMMON_QUALIFIERS, &[Q::PcrConditions, Q::StandardName]),
+            K::StemLoop => (
+                COMMON_QUALIFIERS,
+                &[Q::Function, Q::Operon, Q::StandardName],
+            ),
+            K::IDna => (
+                COMMON_QUALIFIERS,
+                &[Q::Function, Q::Number, Q::StandardName],
+            ),
+            K::MiscStructure | K::PrimTranscript => (
+                COMMON_QUALIFIERS,
+                &[Q::Function, Q::Operon, Q::StandardName],
+            ),
+            K::DLoop | K::PolyASite | K::Sts => (COMMON_QUALIFIERS, &[Q::StandardName]),
+        };
+        common.iter().chain(specific).copied()
+    }
+
+    pub fn allows_qualifier(&self, qualifier: GenbankQualifierKey) -> bool {
+        self.allowed_qualifiers()
+            .any(|allowed| allowed == qualifier)
+    }
+}
+
+impl GenbankQualifierKey {
+    /// Whether the qualifier is written without a value, e.g. `/pseudo`.
+    pub fn is_flag(&self) -> bool {
+        matches!(
+            self,
+            Q::CircularRna
+                | Q::EnvironmentalSample
+                | Q::Focus
+                | Q::Germline
+                | Q::Macronuclear
+                | Q::Proviral
+                | Q::Pseudo
+                | Q::Rearranged
+                | Q::RibosomalSlippage
+                | Q::TransSplicing
+                | Q::Transgenic
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genbank_vocabulary() {
+        assert!(GenbankFeatureKey::ALL.iter().all(|key| key
+            .as_str()
+            .parse::<GenbankFeatureKey>()
+            .ok()
+            == Some(*key)));
+        assert!(GenbankQualifierKey::ALL.iter().all(|key| key
+            .as_str()
+            .parse::<GenbankQualifierKey>()
+            .ok()
+            == Some(*key)));
+
+        assert_eq!("3'UTR".parse().ok(), Some(GenbankFeatureKey::ThreePrimeUtr));
+        assert_eq!("CDS".parse().ok(), Some(GenbankFeatureKey::Cds));
+        assert!("cds".parse::<GenbankFeatureKey>().is_err());
+        assert!("label".parse::<GenbankQualifierKey>().is_err());
+
+        assert!(GenbankFeatureKey::Cds.allows_qualifier(Q::TranslTable));
+        assert!(GenbankFeatureKey::Cds.allows_qualifier(Q::LocusTag));
+        assert!(!GenbankFeatureKey::Gene.allows_qualifier(Q::Translation));
+        assert!(!GenbankFeatureKey::Source.allows_qualifier(Q::Gene));
+        assert!(GenbankFeatureKey::RepOrigin.allows_qualifier(Q::Direction));
+        assert!(Q::Pseudo.is_flag() && !Q::Note.is_flag());
+    }
+}
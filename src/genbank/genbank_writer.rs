@@ -0,0 +1,346 @@
+use std::io::Write;
+
+use crate::{helper::wrap_words, LetterCase, LineEnding};
+
+use super::{
+    genbank_feature_table::{normalize_qualifier_value, GenbankFeatureQualifier},
+    Genbank, GenbankFeature, GenbankMetadataEntry, GenbankSequence,
+};
+
+/// Column at which metadata values start (0-based).
+const METADATA_VALUE_COLUMN: usize = 12;
+/// Column at which feature locations and qualifiers start (0-based).
+const FEATURE_VALUE_COLUMN: usize = 21;
+/// Maximum number of characters per line.
+const LINE_WIDTH: usize = 79;
+/// Number of bases per ORIGIN line.
+const ORIGIN_LINE_BASES: usize = 60;
+/// Number of bases per ORIGIN group.
+const ORIGIN_GROUP_BASES: usize = 10;
+
+/// Indentation of metadata sub-keywords per level.
+const METADATA_SUB_KEYWORD_INDENT: usize = 2;
+
+#[derive(Debug, Clone, Default)]
+pub struct GenbankWriter {
+    line_ending: LineEnding,
+    letter_case: LetterCase,
+}
+
+impl GenbankWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Sets the letter case of the ORIGIN sequence.
+    pub fn letter_case(mut self, letter_case: LetterCase) -> Self {
+        self.letter_case = letter_case;
+        self
+    }
+}
+
+impl GenbankWriter {
+    pub fn write<W: Write>(&self, writer: &mut W, genbank: &Genbank) -> crate::Result<()> {
+        for sequence in genbank.sequences_iter() {
+            self.write_sequence(writer, sequence)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_sequence<W: Write>(
+        &self,
+        writer: &mut W,
+        sequence: &GenbankSequence,
+    ) -> crate::Result<()> {
+        let mut buffer = String::new();
+        self.format_sequence(&mut buffer, sequence);
+        writer.write_all(buffer.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn write_to_string(&self, genbank: &Genbank) -> String {
+        let mut buffer = String::new();
+        for sequence in genbank.sequences_iter() {
+            self.format_sequence(&mut buffer, sequence);
+        }
+        buffer
+    }
+
+    fn format_sequence(&self, buffer: &mut String, sequence: &GenbankSequence) {
+        let raw_layout = sequence.raw_layout();
+
+        for entry in sequence.metadata().entries_iter() {
+            self.format_metadata_entry(buffer, entry, 0);
+        }
+
+        // An empty raw header stands for a missing FEATURES section
+        let features = sequence.features();
+        match &raw_layout.features_header {
+            Some(raw) if !raw.is_empty() || features.features_iter().next().is_none() => {
+                buffer.push_str(raw)
+            }
+            _ => self.push_line(buffer, &format!("{:<21}Location/Qualifiers", "FEATURES")),
+        }
+        for feature in features.features_iter() {
+            self.format_feature(buffer, feature);
+        }
+
+        for section in sequence.unknown_sections() {
+            buffer.push_str(section);
+        }
+
+        match &raw_layout.origin {
+            Some(raw) => buffer.push_str(raw),
+            None => self.format_origin(buffer, sequence.sequence()),
+        }
+        match &raw_layout.terminator {
+            Some(raw) => buffer.push_str(raw),
+            None => self.push_line(buffer, "//"),
+        }
+    }
+
+    fn format_metadata_entry(
+        &self,
+        buffer: &mut String,
+        entry: &GenbankMetadataEntry,
+        depth: usize,
+    ) {
+        match entry.raw() {
+            Some(raw) => buffer.push_str(raw),
+            None => self.format_metadata_value(buffer, entry.key(), entry.value(), depth),
+        }
+
+        for child in entry.children_iter() {
+            self.format_metadata_entry(buffer, child, depth + 1);
+        }
+    }
+
+    fn format_metadata_value(&self, buffer: &mut String, key: &str, value: &str, depth: usize) {
+        let key = format!(
+            "{:indent$}{key:<width$}",
+            "",
+            indent = depth * METADATA_SUB_KEYWORD_INDENT,
+            width = METADATA_VALUE_COLUMN - 1 - depth * METADATA_SUB_KEYWORD_INDENT
+        );
+
+        let mut lines = Vec::new();
+        if key.trim_end() == "LOCUS" {
+            lines.push(value.trim());
+        } else {
+            for line in value.lines().map(str::trim) {
+                lines.extend(wrap_words(
+                    line,
+                    LINE_WIDTH - METADATA_VALUE_COLUMN,
+                    Some(' '),
+                ));
+            }
+        }
+
+        for (index, line) in lines.into_iter().enumerate() {
+            let prefix = if index == 0 { key.as_str() } else { "" };
+            let line = format!("{prefix:<width$} {line}", width = METADATA_VALUE_COLUMN - 1);
+            self.push_line(buffer, line.trim_end());
+        }
+    }
+
+    fn format_feature(&self, buffer: &mut String, feature: &GenbankFeature) {
+        match feature.raw() {
+            Some(raw) => buffer.push_str(raw),
+            None => {
+                let key = format!("     {:<15}", feature.key());
+                self.format_feature_value(buffer, &key, feature.location(), Some(','));
+            }
+        }
+
+        for qualifier in feature.qualifiers() {
+            if let Some(raw) = qualifier.raw() {
+                buffer.push_str(raw);
+                continue;
+            }
+            let value = format_qualifier(qualifier);
+            let separator = (!qualifier.is_sequence()).then_some(' ');
+            self.format_feature_value(buffer, "", &value, separator);
+        }
+    }
+
+    fn format_feature_value(
+        &self,
+        buffer: &mut String,
+        key: &str,
+        value: &str,
+        separator: Option<char>,
+    ) {
+        for (index, line) in wrap_words(value, LINE_WIDTH - FEATURE_VALUE_COLUMN, separator)
+            .into_iter()
+            .enumerate()
+        {
+            let prefix = if index == 0 { key } else { "" };
+            let line = format!("{prefix:<width$} {line}", width = FEATURE_VALUE_COLUMN - 1);
+            self.push_line(buffer, &line);
+        }
+    }
+
+    fn format_origin(&self, buffer: &mut String, sequence: &str) {
+        self.push_line(buffer, "ORIGIN");
+
+        let sequence = self.letter_case.apply(sequence);
+        let bases = sequence.as_bytes();
+        for (line_index, line) in bases.chunks(ORIGIN_LINE_BASES).enumerate() {
+            let mut origin_line = format!("{:>9}", line_index * ORIGIN_LINE_BASES + 1);
+            for group in line.chunks(ORIGIN_GROUP_BASES) {
+                origin_line.push(' ');
+                origin_line.push_str(&String::from_utf8_lossy(group));
+            }
+            self.push_line(buffer, &origin_line);
+        }
+    }
+
+    fn push_line(&self, buffer: &mut String, line: &str) {
+        buffer.push_str(line);
+        buffer.push_str(self.line_ending.as_str());
+    }
+}
+
+fn format_qualifier(qualifier: &GenbankFeatureQualifier) -> String {
+    if qualifier.is_flag() {
+        return format!("/{}", qualifier.key());
+    }
+
+    let value = normalize_qualifier_value(qualifier.key(), qualifier.value());
+
+    if qualifier.is_quoted() {
+        format!("/{}=\"{}\"", qualifier.key(), value.replace('"', "\"\""))
+    } else {
+        format!("/{}={}", qualifier.key(), value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_genbank_write() {
+        let source = indoc! {r#"
+            LOCUS       SCU49845     5028 bp    DNA             PLN       21-JUN-1999
+            DEFINITION  Saccharomyces cerevisiae TCP1-beta gene, partial cds, and Axl2p (AXL2) and Rev7p (REV7) genes, complete cds.
+            ACCESSION   U49845
+            SOURCE      Saccharomyces cerevisiae (baker's yeast)
+              ORGANISM  Saccharomyces cerevisiae
+                        Eukaryota; Fungi; Ascomycota; Saccharomycotina; Saccharomycetes;
+                        Saccharomycetales; Saccharomycetaceae; Saccharomyces.
+            FEATURES             Location/Qualifiers
+                 source          1..5028
+                                 /organism="Saccharomyces cerevisiae"
+                                 /db_xref="taxon:4932"
+                 CDS             <1..206
+                                 /codon_start=3
+                                 /product="TCP1-beta"
+                                 /translation="SSIYNGISTSGLDLNNGTIADMRQLGIVESYKLKRAVVSSASEA
+                                 AEVLLRVDNIIRARPRTANRQHM"
+            ORIGIN
+                    1 gatcctccat atacaacggt atctccacct caggtttaga tctcaacaac ggaaccattg
+                   61 ccgacatgag acagttaggt atcgtcgaga gttacaagct aaaacgagca gtagtcagct
+                  121 ctgcatctga agccgctgaa
+            //
+        "#};
+        let genbank = Genbank::parse(source).unwrap();
+        let written = GenbankWriter::new().write_to_string(&genbank);
+        assert_eq!(
+            written,
+            indoc! {r#"
+                LOCUS       SCU49845     5028 bp    DNA             PLN       21-JUN-1999
+                DEFINITION  Saccharomyces cerevisiae TCP1-beta gene, partial cds, and Axl2p
+                            (AXL2) and Rev7p (REV7) genes, complete cds.
+                ACCESSION   U49845
+                SOURCE      Saccharomyces cerevisiae (baker's yeast)
+                  ORGANISM  Saccharomyces cerevisiae
+                            Eukaryota; Fungi; Ascomycota; Saccharomycotina; Saccharomycetes;
+                            Saccharomycetales; Saccharomycetaceae; Saccharomyces.
+                FEATURES             Location/Qualifiers
+                     source          1..5028
+                                     /organism="Saccharomyces cerevisiae"
+                                     /db_xref="taxon:4932"
+                     CDS             <1..206
+                                     /codon_start=3
+                                     /product="TCP1-beta"
+                                     /translation="SSIYNGISTSGLDLNNGTIADMRQLGIVESYKLKRAVVSSASEA
+                                     AEVLLRVDNIIRARPRTANRQHM"
+                ORIGIN
+                        1 gatcctccat atacaacggt atctccacct caggtttaga tctcaacaac ggaaccattg
+                       61 ccgacatgag acagttaggt atcgtcgaga gttacaagct aaaacgagca gtagtcagct
+                      121 ctgcatctga agccgctgaa
+                //
+            "#}
+        );
+
+        let reparsed = Genbank::parse(&written).unwrap();
+        let sequence = genbank.sequences_iter().next().unwrap();
+        let reparsed = reparsed.sequences_iter().next().unwrap();
+        assert_eq!(reparsed.sequence(), sequence.sequence());
+        assert_eq!(reparsed.metadata().locus(), sequence.metadata().locus());
+        assert_eq!(
+            reparsed.features().features_iter().count(),
+            sequence.features().features_iter().count()
+        );
+    }
+
+    #[test]
+    fn test_genbank_write_lossless() {
+        let source = indoc! {r#"
+            LOCUS       pUC19         2686 bp    DNA     circular SYN 01-JAN-2020
+            DEFINITION  Cloning vector pUC19.   
+            REFERENCE   1  (bases 1 to 2686)
+              AUTHORS   Yanisch-Perron,C., Vieira,J. and Messing,J.
+            REFERENCE   2  (bases 1 to 2686)
+              AUTHORS   Norrander,J., Kempe,T. and Messing,J.
+            FEATURES             Location/Qualifiers
+                 source          1..2686
+                                 /organism="synthetic DNA construct"
+                                 /mol_type="other DNA"
+                 CDS          complement(1626..2486)
+                                 /label=AmpR
+                                 /note="confers resistance to
+                                   ampicillin"
+            BASE COUNT      675 a    683 c    667 g    661 t
+            ORIGIN      
+                    1 tcgcgcgttt cggtgatgac ggtgaaaacc tctgacacat gcagctcccg gagacggtca
+            //
+
+        "#};
+        let mut genbank = Genbank::parse_lossless(source).unwrap();
+        assert_eq!(
+            genbank
+                .sequences_iter()
+                .flat_map(GenbankSequence::unknown_sections)
+                .collect::<Vec<_>>(),
+            ["BASE COUNT      675 a    683 c    667 g    661 t\n"]
+        );
+        assert_eq!(GenbankWriter::new().write_to_string(&genbank), source);
+
+        let cds = genbank
+            .sequences_iter_mut()
+            .next()
+            .unwrap()
+            .features_mut()
+            .features_iter_mut()
+            .find(|feature| feature.key() == "CDS")
+            .unwrap();
+        cds.set_qualifier("label", "bla");
+        let written = GenbankWriter::new().write_to_string(&genbank);
+        assert_eq!(written, source.replace("/label=AmpR", "/label=bla"),);
+
+        let written = GenbankWriter::new().write_to_string(&Genbank::parse(source).unwrap());
+        assert!(written.contains("     CDS             complement(1626..2486)\n"));
+        assert!(written.contains("BASE COUNT      675 a"));
+    }
+}
@@ -1,3 +1,27 @@
+#[cfg(any(
+    feature = "fasta",
+    feature = "fastq",
+    feature = "genbank",
+    feature = "sam"
+))]
+mod line_reader;
+#[cfg(feature = "genbank")]
+mod raw_lines;
 mod rule_ext;
+#[cfg(any(feature = "fasta", feature = "fastq", feature = "genbank"))]
+mod wrap_lines;
 
+#[cfg(any(
+    feature = "fasta",
+    feature = "fastq",
+    feature = "genbank",
+    feature = "sam"
+))]
+pub(crate) use line_reader::LineReader;
+#[cfg(feature = "genbank")]
+pub(crate) use raw_lines::raw_lines;
 pub(crate) use rule_ext::{PairExt, PairOptionExt, RuleExt};
+#[cfg(any(feature = "fasta", feature = "fastq"))]
+pub(crate) use wrap_lines::wrap_lines;
+#[cfg(feature = "genbank")]
+pub(crate) use wrap_lines::wrap_words;
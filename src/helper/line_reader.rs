@@ -0,0 +1,60 @@
+use std::io::{self, BufRead};
+
+/// Line-based reader with a single line of lookahead. Lines keep their line endings.
+#[derive(Debug)]
+pub(crate) struct LineReader<R> {
+    reader: R,
+    peeked: Option<String>,
+}
+
+impl<R: BufRead> LineReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            peeked: None,
+        }
+    }
+
+    pub(crate) fn peek(&mut self) -> io::Result<Option<&str>> {
+        if self.peeked.is_none() {
+            self.peeked = self.read_line()?;
+        }
+        Ok(self.peeked.as_deref())
+    }
+
+    pub(crate) fn next_line(&mut self) -> io::Result<Option<String>> {
+        match self.peeked.take() {
+            Some(line) => Ok(Some(line)),
+            None => self.read_line(),
+        }
+    }
+
+    /// Appends the next line to `buffer`, returning `false` at the end of the input.
+    pub(crate) fn append_line(&mut self, buffer: &mut String) -> io::Result<bool> {
+        let Some(line) = self.next_line()? else {
+            return Ok(false);
+        };
+        buffer.push_str(&line);
+        Ok(true)
+    }
+
+    /// Appends lines to `buffer` for as long as they match `predicate`.
+    pub(crate) fn append_lines_while(
+        &mut self,
+        buffer: &mut String,
+        predicate: impl Fn(&str) -> bool,
+    ) -> io::Result<()> {
+        while self.peek()?.is_some_and(&predicate) {
+            self.append_line(buffer)?;
+        }
+        Ok(())
+    }
+
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line)? {
+            0 => Ok(None),
+            _ => Ok(Some(line)),
+        }
+    }
+}
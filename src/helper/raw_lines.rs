@@ -0,0 +1,14 @@
+/// Returns the text of the full source lines touched by `input[start..end]`,
+/// including the line break that terminates the last line.
+pub(crate) fn raw_lines(input: &str, start: usize, end: usize) -> &str {
+    let start = input[..start].rfind('\n').map_or(0, |index| index + 1);
+    let end = if end > start && input[..end].ends_with('\n') {
+        end
+    } else {
+        input[end..]
+            .find('\n')
+            .map_or(input.len(), |index| end + index + 1)
+    };
+
+    &input[start..end]
+}
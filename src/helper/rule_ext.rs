@@ -6,6 +6,7 @@ where
 {
     type ERROR;
 
+    #[allow(dead_code)]
     fn is(pair: Pair<Self>, expected: Self) -> Option<Pair<Self>> {
         pair.as_rule().eq(&expected).then_some(pair)
     }
@@ -40,6 +41,7 @@ pub(crate) trait PairExt<'a, R>
 where
     R: RuleExt,
 {
+    #[allow(dead_code)]
     fn is(self, expected: R) -> Option<Pair<'a, R>>;
     fn expect(self, expected: R) -> Result<Pair<'a, R>, R::ERROR>;
 }
@@ -0,0 +1,52 @@
+/// Splits `value` into consecutive lines of at most `line_width` characters.
+#[cfg(any(feature = "fasta", feature = "fastq"))]
+pub(crate) fn wrap_lines(value: &str, line_width: Option<usize>) -> impl Iterator<Item = &str> {
+    let mut rest = value;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let split = line_width
+            .and_then(|width| rest.char_indices().nth(width))
+            .map_or(rest.len(), |(index, _)| index);
+        let (line, tail) = rest.split_at(split);
+        rest = tail;
+        Some(line)
+    })
+}
+
+/// Splits `value` into lines of at most `line_width` characters, breaking after the last
+/// `separator` that fits. Lines without a suitable separator are broken at `line_width`.
+/// Whitespace separators are dropped at the line break.
+#[cfg(feature = "genbank")]
+pub(crate) fn wrap_words(value: &str, line_width: usize, separator: Option<char>) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut rest = value;
+
+    while rest.chars().count() > line_width {
+        let limit = rest
+            .char_indices()
+            .nth(line_width)
+            .map_or(rest.len(), |(index, _)| index);
+        let split = separator.and_then(|separator| {
+            if separator.is_whitespace() {
+                // A separator right after the limit still allows a break at the limit
+                let end = rest[limit..]
+                    .chars()
+                    .next()
+                    .map_or(limit, |next| limit + next.len_utf8());
+                let index = rest[..end].rfind(separator).filter(|index| *index > 0)?;
+                Some((index, index + separator.len_utf8()))
+            } else {
+                let index = rest[..limit].rfind(separator)? + separator.len_utf8();
+                Some((index, index))
+            }
+        });
+        let (line_end, rest_start) = split.unwrap_or((limit, limit));
+        lines.push(&rest[..line_end]);
+        rest = &rest[rest_start..];
+    }
+    lines.push(rest);
+
+    lines
+}
@@ -46,3 +46,14 @@ pub mod genbank;
 
 #[cfg(feature = "genbank")]
 pub use genbank::Genbank;
+
+//
+// Public exports
+// Feature: sam
+//
+
+#[cfg(feature = "sam")]
+pub mod sam;
+
+#[cfg(feature = "sam")]
+pub use sam::Sam;
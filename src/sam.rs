@@ -0,0 +1,28 @@
+#![allow(clippy::module_inception)]
+
+mod error;
+mod parser;
+mod sam;
+mod sam_cigar;
+mod sam_flags;
+mod sam_header;
+mod sam_reader;
+mod sam_record;
+mod sam_tag;
+mod sam_writer;
+
+pub(crate) use self::error::{Error, Result};
+pub(crate) use self::parser::Rule;
+
+pub use self::sam::Sam;
+
+pub use self::sam_cigar::{SamCigar, SamCigarOp, SamCigarOpKind};
+pub use self::sam_flags::SamFlags;
+pub use self::sam_header::{
+    SamFileMetadata, SamHeader, SamHeaderFields, SamHeaderLine, SamProgram, SamReadGroup,
+    SamReferenceSequence,
+};
+pub use self::sam_reader::SamReader;
+pub use self::sam_record::SamRecord;
+pub use self::sam_tag::{SamTag, SamTagArray, SamTagValue};
+pub use self::sam_writer::SamWriter;
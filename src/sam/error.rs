@@ -0,0 +1,22 @@
+type SamParseError = pest::error::Error<super::Rule>;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum Error {
+    #[error("SAM parsing error: {0}")]
+    SamParseError(#[from] SamParseError),
+    #[error("SAM compilation error: Expected {expected:?}; Actual: {actual:?}")]
+    SamCompileError {
+        expected: Option<super::Rule>,
+        actual: Option<super::Rule>,
+    },
+    #[error("SAM value error: Invalid {field} {value:?}")]
+    SamValueError { field: &'static str, value: String },
+    #[error("SAM header error: {record_type} line is missing the required {tag} field")]
+    SamHeaderError {
+        record_type: &'static str,
+        tag: &'static str,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Box<Error>>;
@@ -0,0 +1,13 @@
+use pest_derive::Parser;
+
+#[derive(Debug, Parser)]
+#[grammar = "grammar/sam.pest"]
+pub struct SamParser;
+
+impl crate::RuleExt for Rule {
+    type ERROR = super::Error;
+
+    fn to_error(expected: Option<Self>, actual: Option<Self>) -> Self::ERROR {
+        super::Error::SamCompileError { expected, actual }
+    }
+}
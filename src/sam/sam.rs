@@ -0,0 +1,280 @@
+use pest::{iterators::Pair, Parser};
+
+use crate::{helper::*, FileFormat};
+
+use super::{
+    parser::{Rule, SamParser},
+    SamCigar, SamCigarOp, SamCigarOpKind, SamFileMetadata, SamFlags, SamHeader, SamHeaderFields,
+    SamHeaderLine, SamProgram, SamReadGroup, SamRecord, SamReferenceSequence, SamTag, SamTagValue,
+    SamWriter,
+};
+
+#[derive(Debug)]
+pub struct Sam {
+    header: SamHeader,
+    records: Vec<SamRecord>,
+}
+
+impl FileFormat for Sam {
+    const NAME: &'static str = "SAM";
+    const EXTENSIONS: &'static [&'static str] = &["sam"];
+}
+
+impl Sam {
+    pub fn header(&self) -> &SamHeader {
+        &self.header
+    }
+
+    pub fn records_iter(&self) -> impl Iterator<Item = &SamRecord> {
+        self.records.iter()
+    }
+
+    pub fn records_into_iter(self) -> impl Iterator<Item = SamRecord> {
+        self.records.into_iter()
+    }
+
+    /// Writes the header and all records using the default [`SamWriter`] settings.
+    pub fn write<W: std::io::Write>(&self, writer: &mut W) -> crate::Result<()> {
+        SamWriter::default().write(writer, self)
+    }
+}
+
+impl Sam {
+    pub fn parse(source: &str) -> crate::Result<Self> {
+        let root = SamParser::parse(Rule::root, source)
+            .map_err(|err| Box::new(super::Error::SamParseError(err)))?
+            .next()
+            .ok_or_else(|| {
+                Box::new(super::Error::SamCompileError {
+                    expected: Some(Rule::root),
+                    actual: None,
+                })
+            })?;
+
+        Ok(Self::parse_root(root)?)
+    }
+
+    fn parse_root(root: Pair<Rule>) -> super::Result<Self> {
+        let mut header_lines = Vec::new();
+        let mut records = Vec::new();
+
+        for pair in root.into_inner() {
+            match pair.as_rule() {
+                Rule::header_line => header_lines.push(Self::parse_header_line(pair)?),
+                Rule::comment_line => {
+                    let comment = pair.into_inner().next().map_or("", |pair| pair.as_str());
+                    header_lines.push(SamHeaderLine::Comment(comment.to_string()));
+                }
+                Rule::alignment_line => records.push(Self::parse_alignment_line(pair)?),
+                Rule::EOI => break,
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Self {
+            header: SamHeader::new(header_lines),
+            records,
+        })
+    }
+
+    fn parse_header_line(header_line: Pair<Rule>) -> super::Result<SamHeaderLine> {
+        let mut pairs = header_line.into_inner();
+        let record_type = pairs.next().expect_some(Rule::header_record_type)?.as_str();
+
+        let mut fields = Vec::new();
+        for pair in pairs {
+            let mut field = pair.expect(Rule::header_field)?.into_inner();
+            let tag = field.next().expect_some(Rule::header_field_tag)?.as_str();
+            let value = field.next().expect_some(Rule::header_field_value)?.as_str();
+            field.next().expect_none()?;
+            fields.push((tag.to_string(), value.to_string()));
+        }
+        let fields = SamHeaderFields::new(fields);
+
+        Ok(match record_type {
+            "@HD" => SamHeaderLine::FileMetadata(SamFileMetadata::from_fields(fields)?),
+            "@SQ" => SamHeaderLine::ReferenceSequence(SamReferenceSequence::from_fields(fields)?),
+            "@RG" => SamHeaderLine::ReadGroup(SamReadGroup::from_fields(fields)?),
+            "@PG" => SamHeaderLine::Program(SamProgram::from_fields(fields)?),
+            _ => SamHeaderLine::Other {
+                record_type: record_type.to_string(),
+                fields,
+            },
+        })
+    }
+
+    fn parse_alignment_line(alignment_line: Pair<Rule>) -> super::Result<SamRecord> {
+        let mut pairs = alignment_line.into_inner();
+
+        let query_name = pairs.next().expect_some(Rule::qname)?.as_str().to_string();
+        let flags = SamFlags::new(Self::parse_number(pairs.next(), Rule::flag, "FLAG")?);
+        let reference_name = Self::parse_optional_text(pairs.next(), Rule::rname)?;
+        let position = Self::parse_number(pairs.next(), Rule::pos, "POS")?;
+        let mapping_quality: u8 = Self::parse_number(pairs.next(), Rule::mapq, "MAPQ")?;
+        let cigar = Self::parse_cigar(pairs.next())?;
+        let mate_reference_name = Self::parse_optional_text(pairs.next(), Rule::rnext)?;
+        let mate_position = Self::parse_number(pairs.next(), Rule::pnext, "PNEXT")?;
+        let template_length = Self::parse_number(pairs.next(), Rule::tlen, "TLEN")?;
+        let sequence = Self::parse_optional_text(pairs.next(), Rule::seq)?;
+        let quality = Self::parse_optional_text(pairs.next(), Rule::qual)?;
+
+        let mut record = SamRecord::new(query_name);
+        record.set_flags(flags);
+        record.set_reference_name(reference_name);
+        record.set_position((position != 0).then_some(position));
+        record.set_mapping_quality((mapping_quality != 255).then_some(mapping_quality));
+        record.set_cigar(cigar);
+        record.set_mate_reference_name(mate_reference_name);
+        record.set_mate_position((mate_position != 0).then_some(mate_position));
+        record.set_template_length(template_length);
+        record.set_sequence(sequence);
+        record.set_quality(quality);
+        for pair in pairs {
+            record.add_tag(Self::parse_optional_field(pair)?);
+        }
+
+        Ok(record)
+    }
+
+    fn parse_number<T: std::str::FromStr>(
+        pair: Option<Pair<Rule>>,
+        rule: Rule,
+        field: &'static str,
+    ) -> super::Result<T> {
+        let value = pair.expect_some(rule)?.as_str();
+        value.parse().map_err(|_| {
+            Box::new(super::Error::SamValueError {
+                field,
+                value: value.to_string(),
+            })
+        })
+    }
+
+    fn parse_optional_text(pair: Option<Pair<Rule>>, rule: Rule) -> super::Result<Option<String>> {
+        let value = pair.expect_some(rule)?.as_str();
+        Ok((value != "*").then(|| value.to_string()))
+    }
+
+    fn parse_cigar(cigar: Option<Pair<Rule>>) -> super::Result<SamCigar> {
+        let mut ops = Vec::new();
+
+        for pair in cigar.expect_some(Rule::cigar)?.into_inner() {
+            let mut op = pair.expect(Rule::cigar_op)?.into_inner();
+            let length = Self::parse_number(op.next(), Rule::cigar_op_length, "CIGAR")?;
+            let kind = op.next().expect_some(Rule::cigar_op_kind)?.as_str();
+            let kind = kind
+                .chars()
+                .next()
+                .and_then(SamCigarOpKind::from_char)
+                .ok_or_else(|| {
+                    Box::new(super::Error::SamValueError {
+                        field: "CIGAR",
+                        value: kind.to_string(),
+                    })
+                })?;
+            ops.push(SamCigarOp::new(kind, length));
+        }
+
+        Ok(SamCigar::new(ops))
+    }
+
+    fn parse_optional_field(optional_field: Pair<Rule>) -> super::Result<SamTag> {
+        let mut pairs = optional_field.expect(Rule::optional_field)?.into_inner();
+        let name = pairs.next().expect_some(Rule::tag_name)?.as_str();
+        let tag_type = pairs.next().expect_some(Rule::tag_type)?.as_str();
+        let value = pairs.next().expect_some(Rule::tag_value)?.as_str();
+        pairs.next().expect_none()?;
+
+        let value = SamTagValue::parse(tag_type, value).ok_or_else(|| {
+            Box::new(super::Error::SamValueError {
+                field: "optional field",
+                value: format!("{name}:{tag_type}:{value}"),
+            })
+        })?;
+
+        Ok(SamTag::new(name.to_string(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sam::SamTagArray;
+
+    #[test]
+    fn test_sam_parse() {
+        // Example alignment taken from the SAM specification, section 1.1
+        let source = concat!(
+            "@HD\tVN:1.6\tSO:coordinate\n",
+            "@SQ\tSN:ref\tLN:45\n",
+            "@RG\tID:run1\tSM:pUC19\tPL:ILLUMINA\n",
+            "@PG\tID:bwa\tPN:bwa\tVN:0.7.17\tCL:bwa mem ref.fa reads.fq\n",
+            "@CO\tplasmid verification run\n",
+            "r001\t99\tref\t7\t30\t8M2I4M1D3M\t=\t37\t39\tTTAGATAAAGGATACTG\t*\n",
+            "r002\t0\tref\t9\t30\t3S6M1P1I4M\t*\t0\t0\tAAAAGATAAGGATA\t*\n",
+            "r003\t0\tref\t9\t30\t5S6M\t*\t0\t0\tGCCTAAGCTAA\t*\tSA:Z:ref,29,-,6H5M,17,0;\n",
+            "r004\t0\tref\t16\t30\t6M14N5M\t*\t0\t0\tATAGCTTCAGC\t*\n",
+            "r003\t2064\tref\t29\t17\t6H5M\t*\t0\t0\tTAGGC\t*\tSA:Z:ref,9,+,5S6M,30,1;\n",
+            "r001\t147\tref\t37\t30\t9M\t=\t7\t-39\tCAGCGGCAT\t*\tNM:i:1\tXB:B:c,1,-2\tXF:f:0.5\n",
+            "r005\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tIIII\n",
+        );
+        let sam = Sam::parse(source).unwrap();
+
+        let header = sam.header();
+        assert_eq!(header.file_metadata().unwrap().version(), "1.6");
+        assert_eq!(
+            header.file_metadata().unwrap().sort_order(),
+            Some("coordinate")
+        );
+        let reference = header.reference_sequences().next().unwrap();
+        assert_eq!((reference.name(), reference.length()), ("ref", 45));
+        assert_eq!(header.read_groups().next().unwrap().sample(), Some("pUC19"));
+        assert_eq!(
+            header.programs().next().unwrap().command_line(),
+            Some("bwa mem ref.fa reads.fq")
+        );
+        assert_eq!(
+            header.comments().collect::<Vec<_>>(),
+            ["plasmid verification run"]
+        );
+
+        let records = sam.records_iter().collect::<Vec<_>>();
+        assert_eq!(records.len(), 7);
+
+        let r001 = records[0];
+        assert!(r001.flags().is_paired() && r001.flags().is_proper_pair());
+        assert!(r001.flags().is_mate_reverse() && r001.flags().is_first_segment());
+        assert_eq!(r001.position(), Some(7));
+        assert_eq!(r001.mapping_quality(), Some(30));
+        assert_eq!(r001.cigar().to_string(), "8M2I4M1D3M");
+        assert_eq!(r001.cigar().reference_length(), 16);
+        assert_eq!(r001.cigar().query_length(), 17);
+        assert_eq!(r001.mate_reference_name(), Some("ref"));
+        assert_eq!(r001.mate_position(), Some(37));
+        assert_eq!(r001.quality(), None);
+
+        let r003 = records[4];
+        assert!(r003.flags().is_supplementary() && r003.flags().is_reverse());
+        assert_eq!(
+            r003.get_tag("SA"),
+            Some(&SamTagValue::String("ref,9,+,5S6M,30,1;".into()))
+        );
+
+        let r001_mate = records[5];
+        assert_eq!(r001_mate.template_length(), -39);
+        assert_eq!(r001_mate.get_tag("NM"), Some(&SamTagValue::Int(1)));
+        assert_eq!(
+            r001_mate.get_tag("XB"),
+            Some(&SamTagValue::Array(SamTagArray::Int8(vec![1, -2])))
+        );
+        assert_eq!(r001_mate.get_tag("XF"), Some(&SamTagValue::Float(0.5)));
+
+        let r005 = records[6];
+        assert!(r005.flags().is_unmapped());
+        assert_eq!(r005.reference_name(), None);
+        assert_eq!(r005.position(), None);
+        assert_eq!(r005.mapping_quality(), None);
+        assert!(r005.cigar().is_empty());
+        assert_eq!(r005.quality(), Some("IIII"));
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SamCigarOpKind {
+    /// `M`: alignment match (can be a sequence match or mismatch)
+    Match,
+    /// `I`: insertion to the reference
+    Insertion,
+    /// `D`: deletion from the reference
+    Deletion,
+    /// `N`: skipped region from the reference
+    Skip,
+    /// `S`: soft clipping (clipped sequences present in SEQ)
+    SoftClip,
+    /// `H`: hard clipping (clipped sequences not present in SEQ)
+    HardClip,
+    /// `P`: padding (silent deletion from padded reference)
+    Padding,
+    /// `=`: sequence match
+    SequenceMatch,
+    /// `X`: sequence mismatch
+    SequenceMismatch,
+}
+
+impl SamCigarOpKind {
+    pub(crate) fn from_char(value: char) -> Option<Self> {
+        Some(match value {
+            'M' => Self::Match,
+            'I' => Self::Insertion,
+            'D' => Self::Deletion,
+            'N' => Self::Skip,
+            'S' => Self::SoftClip,
+            'H' => Self::HardClip,
+            'P' => Self::Padding,
+            '=' => Self::SequenceMatch,
+            'X' => Self::SequenceMismatch,
+            _ => return None,
+        })
+    }
+
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Match => 'M',
+            Self::Insertion => 'I',
+            Self::Deletion => 'D',
+            Self::Skip => 'N',
+            Self::SoftClip => 'S',
+            Self::HardClip => 'H',
+            Self::Padding => 'P',
+            Self::SequenceMatch => '=',
+            Self::SequenceMismatch => 'X',
+        }
+    }
+
+    pub fn consumes_query(&self) -> bool {
+        matches!(
+            self,
+            Self::Match
+                | Self::Insertion
+                | Self::SoftClip
+                | Self::SequenceMatch
+                | Self::SequenceMismatch
+        )
+    }
+
+    pub fn consumes_reference(&self) -> bool {
+        matches!(
+            self,
+            Self::Match
+                | Self::Deletion
+                | Self::Skip
+                | Self::SequenceMatch
+                | Self::SequenceMismatch
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SamCigarOp {
+    kind: SamCigarOpKind,
+    length: u32,
+}
+
+impl SamCigarOp {
+    pub fn new(kind: SamCigarOpKind, length: u32) -> Self {
+        Self { kind, length }
+    }
+
+    pub fn kind(&self) -> SamCigarOpKind {
+        self.kind
+    }
+
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+/// Parsed CIGAR string. An empty CIGAR corresponds to `*`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SamCigar {
+    ops: Vec<SamCigarOp>,
+}
+
+impl SamCigar {
+    pub fn new(ops: Vec<SamCigarOp>) -> Self {
+        Self { ops }
+    }
+
+    pub fn ops_iter(&self) -> impl Iterator<Item = &SamCigarOp> {
+        self.ops.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Number of reference bases covered by the alignment.
+    pub fn reference_length(&self) -> u64 {
+        self.ops
+            .iter()
+            .filter(|op| op.kind.consumes_reference())
+            .map(|op| u64::from(op.length))
+            .sum()
+    }
+
+    /// Number of bases of SEQ described by the alignment.
+    pub fn query_length(&self) -> u64 {
+        self.ops
+            .iter()
+            .filter(|op| op.kind.consumes_query())
+            .map(|op| u64::from(op.length))
+            .sum()
+    }
+}
+
+impl fmt::Display for SamCigar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ops.is_empty() {
+            return f.write_str("*");
+        }
+        for op in &self.ops {
+            write!(f, "{}{}", op.length, op.kind.as_char())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+/// Bitwise FLAG field of an alignment record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SamFlags(u16);
+
+impl SamFlags {
+    /// Template having multiple segments in sequencing
+    pub const PAIRED: u16 = 0x1;
+    /// Each segment properly aligned according to the aligner
+    pub const PROPER_PAIR: u16 = 0x2;
+    /// Segment unmapped
+    pub const UNMAPPED: u16 = 0x4;
+    /// Next segment in the template unmapped
+    pub const MATE_UNMAPPED: u16 = 0x8;
+    /// SEQ being reverse complemented
+    pub const REVERSE: u16 = 0x10;
+    /// SEQ of the next segment in the template being reverse complemented
+    pub const MATE_REVERSE: u16 = 0x20;
+    /// The first segment in the template
+    pub const FIRST_SEGMENT: u16 = 0x40;
+    /// The last segment in the template
+    pub const LAST_SEGMENT: u16 = 0x80;
+    /// Secondary alignment
+    pub const SECONDARY: u16 = 0x100;
+    /// Not passing filters, such as platform/vendor quality controls
+    pub const QC_FAIL: u16 = 0x200;
+    /// PCR or optical duplicate
+    pub const DUPLICATE: u16 = 0x400;
+    /// Supplementary alignment
+    pub const SUPPLEMENTARY: u16 = 0x800;
+
+    pub fn new(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub fn contains(&self, flag: u16) -> bool {
+        self.0 & flag == flag
+    }
+
+    pub fn is_paired(&self) -> bool {
+        self.contains(Self::PAIRED)
+    }
+
+    pub fn is_proper_pair(&self) -> bool {
+        self.contains(Self::PROPER_PAIR)
+    }
+
+    pub fn is_unmapped(&self) -> bool {
+        self.contains(Self::UNMAPPED)
+    }
+
+    pub fn is_mate_unmapped(&self) -> bool {
+        self.contains(Self::MATE_UNMAPPED)
+    }
+
+    pub fn is_reverse(&self) -> bool {
+        self.contains(Self::REVERSE)
+    }
+
+    pub fn is_mate_reverse(&self) -> bool {
+        self.contains(Self::MATE_REVERSE)
+    }
+
+    pub fn is_first_segment(&self) -> bool {
+        self.contains(Self::FIRST_SEGMENT)
+    }
+
+    pub fn is_last_segment(&self) -> bool {
+        self.contains(Self::LAST_SEGMENT)
+    }
+
+    pub fn is_secondary(&self) -> bool {
+        self.contains(Self::SECONDARY)
+    }
+
+    pub fn is_qc_fail(&self) -> bool {
+        self.contains(Self::QC_FAIL)
+    }
+
+    pub fn is_duplicate(&self) -> bool {
+        self.contains(Self::DUPLICATE)
+    }
+
+    pub fn is_supplementary(&self) -> bool {
+        self.contains(Self::SUPPLEMENTARY)
+    }
+}
@@ -0,0 +1,297 @@
+/// The `TAG:VALUE` fields of a header line in their original order.
+#[derive(Debug, Clone, Default)]
+pub struct SamHeaderFields {
+    fields: Vec<(String, String)>,
+}
+
+impl SamHeaderFields {
+    pub(crate) fn new(fields: Vec<(String, String)>) -> Self {
+        Self { fields }
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(key, _)| key == tag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .map(|(tag, value)| (tag.as_str(), value.as_str()))
+    }
+
+    fn require(&self, record_type: &'static str, tag: &'static str) -> super::Result<&str> {
+        self.get(tag)
+            .ok_or_else(|| Box::new(super::Error::SamHeaderError { record_type, tag }))
+    }
+}
+
+/// File-level metadata (`@HD`).
+#[derive(Debug, Clone)]
+pub struct SamFileMetadata {
+    version: String,
+    fields: SamHeaderFields,
+}
+
+impl SamFileMetadata {
+    pub(crate) fn from_fields(fields: SamHeaderFields) -> super::Result<Self> {
+        Ok(Self {
+            version: fields.require("@HD", "VN")?.to_string(),
+            fields,
+        })
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn sort_order(&self) -> Option<&str> {
+        self.fields.get("SO")
+    }
+
+    pub fn group_order(&self) -> Option<&str> {
+        self.fields.get("GO")
+    }
+
+    pub fn sub_sort_order(&self) -> Option<&str> {
+        self.fields.get("SS")
+    }
+
+    pub fn fields(&self) -> &SamHeaderFields {
+        &self.fields
+    }
+}
+
+/// Reference sequence dictionary entry (`@SQ`).
+#[derive(Debug, Clone)]
+pub struct SamReferenceSequence {
+    name: String,
+    length: u64,
+    fields: SamHeaderFields,
+}
+
+impl SamReferenceSequence {
+    pub(crate) fn from_fields(fields: SamHeaderFields) -> super::Result<Self> {
+        let name = fields.require("@SQ", "SN")?.to_string();
+        let length = fields.require("@SQ", "LN")?;
+        let length = length.parse().map_err(|_| {
+            Box::new(super::Error::SamValueError {
+                field: "@SQ LN",
+                value: length.to_string(),
+            })
+        })?;
+
+        Ok(Self {
+            name,
+            length,
+            fields,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    pub fn alternate_locus(&self) -> Option<&str> {
+        self.fields.get("AH")
+    }
+
+    pub fn assembly(&self) -> Option<&str> {
+        self.fields.get("AS")
+    }
+
+    pub fn md5(&self) -> Option<&str> {
+        self.fields.get("M5")
+    }
+
+    pub fn species(&self) -> Option<&str> {
+        self.fields.get("SP")
+    }
+
+    pub fn topology(&self) -> Option<&str> {
+        self.fields.get("TP")
+    }
+
+    pub fn uri(&self) -> Option<&str> {
+        self.fields.get("UR")
+    }
+
+    pub fn fields(&self) -> &SamHeaderFields {
+        &self.fields
+    }
+}
+
+/// Read group (`@RG`).
+#[derive(Debug, Clone)]
+pub struct SamReadGroup {
+    id: String,
+    fields: SamHeaderFields,
+}
+
+impl SamReadGroup {
+    pub(crate) fn from_fields(fields: SamHeaderFields) -> super::Result<Self> {
+        Ok(Self {
+            id: fields.require("@RG", "ID")?.to_string(),
+            fields,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn sample(&self) -> Option<&str> {
+        self.fields.get("SM")
+    }
+
+    pub fn library(&self) -> Option<&str> {
+        self.fields.get("LB")
+    }
+
+    pub fn platform(&self) -> Option<&str> {
+        self.fields.get("PL")
+    }
+
+    pub fn platform_unit(&self) -> Option<&str> {
+        self.fields.get("PU")
+    }
+
+    pub fn sequencing_center(&self) -> Option<&str> {
+        self.fields.get("CN")
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.fields.get("DS")
+    }
+
+    pub fn fields(&self) -> &SamHeaderFields {
+        &self.fields
+    }
+}
+
+/// Program that produced or processed the alignments (`@PG`).
+#[derive(Debug, Clone)]
+pub struct SamProgram {
+    id: String,
+    fields: SamHeaderFields,
+}
+
+impl SamProgram {
+    pub(crate) fn from_fields(fields: SamHeaderFields) -> super::Result<Self> {
+        Ok(Self {
+            id: fields.require("@PG", "ID")?.to_string(),
+            fields,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.fields.get("PN")
+    }
+
+    pub fn command_line(&self) -> Option<&str> {
+        self.fields.get("CL")
+    }
+
+    pub fn previous_program(&self) -> Option<&str> {
+        self.fields.get("PP")
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.fields.get("DS")
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.fields.get("VN")
+    }
+
+    pub fn fields(&self) -> &SamHeaderFields {
+        &self.fields
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SamHeaderLine {
+    FileMetadata(SamFileMetadata),
+    ReferenceSequence(SamReferenceSequence),
+    ReadGroup(SamReadGroup),
+    Program(SamProgram),
+    Comment(String),
+    /// A header line with a record type not defined by the specification.
+    Other {
+        record_type: String,
+        fields: SamHeaderFields,
+    },
+}
+
+impl SamHeaderLine {
+    pub fn record_type(&self) -> &str {
+        match self {
+            SamHeaderLine::FileMetadata(_) => "@HD",
+            SamHeaderLine::ReferenceSequence(_) => "@SQ",
+            SamHeaderLine::ReadGroup(_) => "@RG",
+            SamHeaderLine::Program(_) => "@PG",
+            SamHeaderLine::Comment(_) => "@CO",
+            SamHeaderLine::Other { record_type, .. } => record_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SamHeader {
+    lines: Vec<SamHeaderLine>,
+}
+
+impl SamHeader {
+    pub(crate) fn new(lines: Vec<SamHeaderLine>) -> Self {
+        Self { lines }
+    }
+
+    pub fn lines_iter(&self) -> impl Iterator<Item = &SamHeaderLine> {
+        self.lines.iter()
+    }
+
+    pub fn file_metadata(&self) -> Option<&SamFileMetadata> {
+        self.lines.iter().find_map(|line| match line {
+            SamHeaderLine::FileMetadata(metadata) => Some(metadata),
+            _ => None,
+        })
+    }
+
+    pub fn reference_sequences(&self) -> impl Iterator<Item = &SamReferenceSequence> {
+        self.lines.iter().filter_map(|line| match line {
+            SamHeaderLine::ReferenceSequence(reference_sequence) => Some(reference_sequence),
+            _ => None,
+        })
+    }
+
+    pub fn read_groups(&self) -> impl Iterator<Item = &SamReadGroup> {
+        self.lines.iter().filter_map(|line| match line {
+            SamHeaderLine::ReadGroup(read_group) => Some(read_group),
+            _ => None,
+        })
+    }
+
+    pub fn programs(&self) -> impl Iterator<Item = &SamProgram> {
+        self.lines.iter().filter_map(|line| match line {
+            SamHeaderLine::Program(program) => Some(program),
+            _ => None,
+        })
+    }
+
+    pub fn comments(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            SamHeaderLine::Comment(comment) => Some(comment.as_str()),
+            _ => None,
+        })
+    }
+}
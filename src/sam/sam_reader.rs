@@ -0,0 +1,99 @@
+use std::io::BufRead;
+
+use crate::helper::LineReader;
+
+use super::{Rule, Sam, SamHeader, SamRecord};
+
+/// Reads SAM alignment records one at a time from a [`BufRead`] source.
+///
+/// The header section is read when the reader is created. Afterwards, only a single
+/// alignment line is held in memory at a time.
+#[derive(Debug)]
+pub struct SamReader<R> {
+    lines: LineReader<R>,
+    header: SamHeader,
+    failed: bool,
+}
+
+impl<R: BufRead> SamReader<R> {
+    pub fn new(reader: R) -> crate::Result<Self> {
+        let mut lines = LineReader::new(reader);
+        let mut header = String::new();
+        lines.append_lines_while(&mut header, |line| line.starts_with('@'))?;
+        let header = Sam::parse(&header)?.header().clone();
+
+        Ok(Self {
+            lines,
+            header,
+            failed: false,
+        })
+    }
+
+    pub fn header(&self) -> &SamHeader {
+        &self.header
+    }
+
+    fn parse_record(record: &str) -> crate::Result<SamRecord> {
+        Sam::parse(record)?
+            .records_into_iter()
+            .next()
+            .ok_or_else(|| {
+                Box::new(super::Error::SamCompileError {
+                    expected: Some(Rule::alignment_line),
+                    actual: None,
+                })
+                .into()
+            })
+    }
+}
+
+impl<R: BufRead> Iterator for SamReader<R> {
+    type Item = crate::Result<SamRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        loop {
+            match self.lines.next_line() {
+                Ok(Some(line)) if line.trim().is_empty() => continue,
+                Ok(line) => return line.map(|line| Self::parse_record(&line)),
+                Err(err) => {
+                    self.failed = true;
+                    return Some(Err(err.into()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sam_reader() {
+        let source = concat!(
+            "@HD\tVN:1.6\tSO:unsorted\n",
+            "@SQ\tSN:pUC19\tLN:2686\n",
+            "read1\t0\tpUC19\t7\t60\t4M\t*\t0\t0\tACGT\tIIII\n",
+            "read2\t16\tpUC19\t9\t60\t4M\t*\t0\t0\tTTGA\tIIII\tNM:i:0\n",
+        );
+        let expected = Sam::parse(source).unwrap();
+        let mut reader = SamReader::new(source.as_bytes()).unwrap();
+        assert_eq!(
+            reader.header().reference_sequences().next().unwrap().name(),
+            "pUC19"
+        );
+
+        let streamed = reader.by_ref().collect::<crate::Result<Vec<_>>>().unwrap();
+        assert_eq!(streamed.len(), 2);
+        for (expected, streamed) in expected.records_iter().zip(&streamed) {
+            assert_eq!(expected.query_name(), streamed.query_name());
+            assert_eq!(expected.flags(), streamed.flags());
+            assert_eq!(expected.cigar(), streamed.cigar());
+            assert_eq!(expected.tags_iter().count(), streamed.tags_iter().count());
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use super::{SamCigar, SamFlags, SamTag, SamTagValue};
+
+/// A single alignment line.
+#[derive(Debug, Clone)]
+pub struct SamRecord {
+    pub(crate) query_name: String,
+    pub(crate) flags: SamFlags,
+    pub(crate) reference_name: Option<String>,
+    pub(crate) position: Option<u32>,
+    pub(crate) mapping_quality: Option<u8>,
+    pub(crate) cigar: SamCigar,
+    pub(crate) mate_reference_name: Option<String>,
+    pub(crate) mate_position: Option<u32>,
+    pub(crate) template_length: i64,
+    pub(crate) sequence: Option<String>,
+    pub(crate) quality: Option<String>,
+    pub(crate) tags: Vec<SamTag>,
+}
+
+impl SamRecord {
+    /// Creates a record with the given query name and all other fields unset, i.e. `0` or `*`.
+    pub fn new(query_name: String) -> Self {
+        Self {
+            query_name,
+            flags: SamFlags::default(),
+            reference_name: None,
+            position: None,
+            mapping_quality: None,
+            cigar: SamCigar::default(),
+            mate_reference_name: None,
+            mate_position: None,
+            template_length: 0,
+            sequence: None,
+            quality: None,
+            tags: Vec::new(),
+        }
+    }
+
+    pub fn query_name(&self) -> &str {
+        &self.query_name
+    }
+
+    pub fn flags(&self) -> SamFlags {
+        self.flags
+    }
+
+    /// Reference sequence name, `None` for `*`.
+    pub fn reference_name(&self) -> Option<&str> {
+        self.reference_name.as_deref()
+    }
+
+    /// 1-based leftmost mapping position, `None` for `0`.
+    pub fn position(&self) -> Option<u32> {
+        self.position
+    }
+
+    /// Mapping quality, `None` for `255` (unavailable).
+    pub fn mapping_quality(&self) -> Option<u8> {
+        self.mapping_quality
+    }
+
+    pub fn cigar(&self) -> &SamCigar {
+        &self.cigar
+    }
+
+    /// Reference sequence name of the next segment, with `=` resolved to [`Self::reference_name`].
+    pub fn mate_reference_name(&self) -> Option<&str> {
+        match self.mate_reference_name.as_deref() {
+            Some("=") => self.reference_name(),
+            name => name,
+        }
+    }
+
+    /// 1-based position of the next segment, `None` for `0`.
+    pub fn mate_position(&self) -> Option<u32> {
+        self.mate_position
+    }
+
+    pub fn template_length(&self) -> i64 {
+        self.template_length
+    }
+
+    /// Segment sequence, `None` for `*`.
+    pub fn sequence(&self) -> Option<&str> {
+        self.sequence.as_deref()
+    }
+
+    /// Phred+33 encoded base qualities, `None` for `*`.
+    pub fn quality(&self) -> Option<&str> {
+        self.quality.as_deref()
+    }
+
+    pub fn tags_iter(&self) -> impl Iterator<Item = &SamTag> {
+        self.tags.iter()
+    }
+
+    pub fn get_tag(&self, name: &str) -> Option<&SamTagValue> {
+        self.tags
+            .iter()
+            .find(|tag| tag.name() == name)
+            .map(SamTag::value)
+    }
+}
+
+impl SamRecord {
+    pub fn set_flags(&mut self, flags: SamFlags) {
+        self.flags = flags;
+    }
+
+    pub fn set_reference_name(&mut self, reference_name: Option<String>) {
+        self.reference_name = reference_name;
+    }
+
+    pub fn set_position(&mut self, position: Option<u32>) {
+        self.position = position;
+    }
+
+    pub fn set_mapping_quality(&mut self, mapping_quality: Option<u8>) {
+        self.mapping_quality = mapping_quality;
+    }
+
+    pub fn set_cigar(&mut self, cigar: SamCigar) {
+        self.cigar = cigar;
+    }
+
+    /// Reference sequence name of the next segment, `=` for the same reference.
+    pub fn set_mate_reference_name(&mut self, mate_reference_name: Option<String>) {
+        self.mate_reference_name = mate_reference_name;
+    }
+
+    pub fn set_mate_position(&mut self, mate_position: Option<u32>) {
+        self.mate_position = mate_position;
+    }
+
+    pub fn set_template_length(&mut self, template_length: i64) {
+        self.template_length = template_length;
+    }
+
+    pub fn set_sequence(&mut self, sequence: Option<String>) {
+        self.sequence = sequence;
+    }
+
+    /// Phred+33 encoded base qualities.
+    pub fn set_quality(&mut self, quality: Option<String>) {
+        self.quality = quality;
+    }
+
+    pub fn add_tag(&mut self, tag: SamTag) {
+        self.tags.push(tag);
+    }
+}
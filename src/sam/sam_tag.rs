@@ -0,0 +1,160 @@
+use std::fmt;
+
+/// Numeric array of a `B`-typed optional field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SamTagArray {
+    Int8(Vec<i8>),
+    UInt8(Vec<u8>),
+    Int16(Vec<i16>),
+    UInt16(Vec<u16>),
+    Int32(Vec<i32>),
+    UInt32(Vec<u32>),
+    Float(Vec<f32>),
+}
+
+impl SamTagArray {
+    fn parse(value: &str) -> Option<Self> {
+        fn values<T: std::str::FromStr>(values: &[&str]) -> Option<Vec<T>> {
+            values.iter().map(|value| value.parse().ok()).collect()
+        }
+
+        let (subtype, rest) = value.split_at_checked(1)?;
+        let items = rest
+            .strip_prefix(',')
+            .map(|rest| rest.split(',').collect::<Vec<_>>())
+            .unwrap_or_default();
+        if items.is_empty() && !rest.is_empty() {
+            return None;
+        }
+
+        Some(match subtype {
+            "c" => Self::Int8(values(&items)?),
+            "C" => Self::UInt8(values(&items)?),
+            "s" => Self::Int16(values(&items)?),
+            "S" => Self::UInt16(values(&items)?),
+            "i" => Self::Int32(values(&items)?),
+            "I" => Self::UInt32(values(&items)?),
+            "f" => Self::Float(values(&items)?),
+            _ => return None,
+        })
+    }
+
+    fn subtype(&self) -> char {
+        match self {
+            Self::Int8(_) => 'c',
+            Self::UInt8(_) => 'C',
+            Self::Int16(_) => 's',
+            Self::UInt16(_) => 'S',
+            Self::Int32(_) => 'i',
+            Self::UInt32(_) => 'I',
+            Self::Float(_) => 'f',
+        }
+    }
+}
+
+impl fmt::Display for SamTagArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn items<T: fmt::Display>(f: &mut fmt::Formatter<'_>, values: &[T]) -> fmt::Result {
+            values.iter().try_for_each(|value| write!(f, ",{value}"))
+        }
+
+        write!(f, "{}", self.subtype())?;
+        match self {
+            Self::Int8(values) => items(f, values),
+            Self::UInt8(values) => items(f, values),
+            Self::Int16(values) => items(f, values),
+            Self::UInt16(values) => items(f, values),
+            Self::Int32(values) => items(f, values),
+            Self::UInt32(values) => items(f, values),
+            Self::Float(values) => items(f, values),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SamTagValue {
+    /// `A`: printable character
+    Char(char),
+    /// `i`: signed integer
+    Int(i64),
+    /// `f`: single-precision floating number
+    Float(f32),
+    /// `Z`: printable string, including space
+    String(String),
+    /// `H`: byte array in hex format
+    Hex(String),
+    /// `B`: integer or numeric array
+    Array(SamTagArray),
+}
+
+impl SamTagValue {
+    pub(crate) fn parse(tag_type: &str, value: &str) -> Option<Self> {
+        Some(match tag_type {
+            "A" => {
+                let mut chars = value.chars();
+                let value = chars.next()?;
+                chars.next().is_none().then_some(Self::Char(value))?
+            }
+            "i" => Self::Int(value.parse().ok()?),
+            "f" => Self::Float(value.parse().ok()?),
+            "Z" => Self::String(value.to_string()),
+            "H" => {
+                let is_hex =
+                    value.len().is_multiple_of(2) && value.chars().all(|c| c.is_ascii_hexdigit());
+                is_hex.then(|| Self::Hex(value.to_string()))?
+            }
+            "B" => Self::Array(SamTagArray::parse(value)?),
+            _ => return None,
+        })
+    }
+
+    pub fn tag_type(&self) -> char {
+        match self {
+            Self::Char(_) => 'A',
+            Self::Int(_) => 'i',
+            Self::Float(_) => 'f',
+            Self::String(_) => 'Z',
+            Self::Hex(_) => 'H',
+            Self::Array(_) => 'B',
+        }
+    }
+}
+
+impl fmt::Display for SamTagValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Char(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::String(value) | Self::Hex(value) => f.write_str(value),
+            Self::Array(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Optional `TAG:TYPE:VALUE` field of an alignment record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SamTag {
+    name: String,
+    value: SamTagValue,
+}
+
+impl SamTag {
+    pub fn new(name: String, value: SamTagValue) -> Self {
+        Self { name, value }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &SamTagValue {
+        &self.value
+    }
+}
+
+impl fmt::Display for SamTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.name, self.value.tag_type(), self.value)
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::Write;
+
+use crate::LineEnding;
+
+use super::{Sam, SamHeader, SamHeaderFields, SamHeaderLine, SamRecord};
+
+#[derive(Debug, Clone, Default)]
+pub struct SamWriter {
+    line_ending: LineEnding,
+}
+
+impl SamWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+}
+
+impl SamWriter {
+    pub fn write<W: Write>(&self, writer: &mut W, sam: &Sam) -> crate::Result<()> {
+        self.write_header(writer, sam.header())?;
+        for record in sam.records_iter() {
+            self.write_record(writer, record)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_header<W: Write>(&self, writer: &mut W, header: &SamHeader) -> crate::Result<()> {
+        let mut buffer = String::new();
+        self.format_header(&mut buffer, header);
+        writer.write_all(buffer.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn write_record<W: Write>(&self, writer: &mut W, record: &SamRecord) -> crate::Result<()> {
+        let mut buffer = String::new();
+        self.format_record(&mut buffer, record);
+        writer.write_all(buffer.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn write_to_string(&self, sam: &Sam) -> String {
+        let mut buffer = String::new();
+        self.format_header(&mut buffer, sam.header());
+        for record in sam.records_iter() {
+            self.format_record(&mut buffer, record);
+        }
+        buffer
+    }
+
+    fn format_header(&self, buffer: &mut String, header: &SamHeader) {
+        for line in header.lines_iter() {
+            buffer.push_str(line.record_type());
+            match line {
+                SamHeaderLine::FileMetadata(line) => format_header_fields(buffer, line.fields()),
+                SamHeaderLine::ReferenceSequence(line) => {
+                    format_header_fields(buffer, line.fields())
+                }
+                SamHeaderLine::ReadGroup(line) => format_header_fields(buffer, line.fields()),
+                SamHeaderLine::Program(line) => format_header_fields(buffer, line.fields()),
+                SamHeaderLine::Comment(comment) => {
+                    buffer.push('\t');
+                    buffer.push_str(comment);
+                }
+                SamHeaderLine::Other { fields, .. } => format_header_fields(buffer, fields),
+            }
+            buffer.push_str(self.line_ending.as_str());
+        }
+    }
+
+    fn format_record(&self, buffer: &mut String, record: &SamRecord) {
+        let fields = [
+            record.query_name.clone(),
+            record.flags.bits().to_string(),
+            record.reference_name.as_deref().unwrap_or("*").to_string(),
+            record.position.unwrap_or(0).to_string(),
+            record.mapping_quality.unwrap_or(255).to_string(),
+            record.cigar.to_string(),
+            record
+                .mate_reference_name
+                .as_deref()
+                .unwrap_or("*")
+                .to_string(),
+            record.mate_position.unwrap_or(0).to_string(),
+            record.template_length.to_string(),
+            record.sequence.as_deref().unwrap_or("*").to_string(),
+            record.quality.as_deref().unwrap_or("*").to_string(),
+        ];
+        buffer.push_str(&fields.join("\t"));
+
+        for tag in &record.tags {
+            buffer.push('\t');
+            buffer.push_str(&tag.to_string());
+        }
+        buffer.push_str(self.line_ending.as_str());
+    }
+}
+
+fn format_header_fields(buffer: &mut String, fields: &SamHeaderFields) {
+    for (tag, value) in fields.iter() {
+        buffer.push('\t');
+        buffer.push_str(tag);
+        buffer.push(':');
+        buffer.push_str(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sam::{SamFlags, SamTag, SamTagArray, SamTagValue};
+
+    #[test]
+    fn test_sam_write() {
+        let source = concat!(
+            "@HD\tVN:1.6\tSO:coordinate\n",
+            "@SQ\tSN:pUC19\tLN:2686\tTP:circular\n",
+            "@CO\tround trip\n",
+            "read1\t99\tpUC19\t7\t60\t8M2I4M1D3M\t=\t37\t39\tTTAGATAAAGGATACTG\t*\tNM:i:3\tMD:Z:8^A3\n",
+            "read2\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tII#I\tXB:B:f,0.25,1\n",
+        );
+        let sam = Sam::parse(source).unwrap();
+        assert_eq!(SamWriter::new().write_to_string(&sam), source);
+
+        let mut buffer = Vec::new();
+        sam.write(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), source);
+
+        let mut record = SamRecord::new("read2".to_string());
+        record.set_flags(SamFlags::new(SamFlags::UNMAPPED));
+        record.set_sequence(Some("ACGT".to_string()));
+        record.set_quality(Some("II#I".to_string()));
+        record.add_tag(SamTag::new(
+            "XB".to_string(),
+            SamTagValue::Array(SamTagArray::Float(vec![0.25, 1.0])),
+        ));
+        let mut buffer = Vec::new();
+        SamWriter::new().write_record(&mut buffer, &record).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "read2\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tII#I\tXB:B:f,0.25,1\n"
+        );
+    }
+}